@@ -0,0 +1,58 @@
+//! Benchmarks parsing and each part of every registered solver whose input is cached in `input/`.
+//! Each benchmark is named `{year}/{day}/{step}`, so `cargo bench -- 2024/06` benchmarks a single
+//! puzzle and `cargo bench -- part2` only the second parts. Inputs are read from the directory of
+//! the profile named by `AOC_PROFILE`, or of the default profile. Each step is run once before it
+//! is benchmarked, and skipped if it panics.
+
+use std::fs;
+
+use advent_of_code::{
+    registry,
+    utils::v2::{config::Config, solver::input_path, worker},
+};
+use criterion::Criterion;
+
+fn main() {
+    let mut criterion = Criterion::default().configure_from_args();
+    let registry = registry();
+    let profile = Config::load()
+        .and_then(|config| config.profile(None))
+        .expect("the config should be valid");
+    let mut skipped = 0;
+
+    for solver in registry.iter() {
+        let (year, day) = (solver.year(), solver.day());
+        let puzzle = format!("{year}/{day:02}");
+        let Ok(input) = fs::read_to_string(input_path(&profile, year, day, None)) else {
+            skipped += 1;
+            continue;
+        };
+        let input = input.trim();
+        let steps = match worker::catch(|| solver.steps(input)) {
+            Ok(Ok(steps)) => steps,
+            Ok(Err(e)) => {
+                eprintln!("Skipping {puzzle}, its input could not be parsed: {e}");
+                continue;
+            }
+            Err(message) => {
+                eprintln!("Skipping {puzzle}, parsing its input panicked: {message}");
+                continue;
+            }
+        };
+
+        let mut group = criterion.benchmark_group(&puzzle);
+        for mut step in steps {
+            if let Err(message) = worker::catch(&mut step.run) {
+                eprintln!("Skipping {puzzle}/{}, it panicked: {message}", step.name);
+                continue;
+            }
+            group.bench_function(step.name, |b| b.iter(&mut step.run));
+        }
+        group.finish();
+    }
+
+    if skipped > 0 {
+        eprintln!("Skipped {skipped} puzzles without a cached input in input/");
+    }
+    criterion.final_summary();
+}
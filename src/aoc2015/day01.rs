@@ -1,3 +1,5 @@
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     input
         .trim()
@@ -20,6 +22,8 @@ pub fn solve_part_two(input: &String) -> i32 {
         + 1
 }
 
+legacy_solver!(2015, 1);
+
 #[cfg(test)]
 mod tests {
     use crate::aoc2015::day01::{solve_part_one, solve_part_two};
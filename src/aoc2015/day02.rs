@@ -1,3 +1,5 @@
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     input.lines().map(wrapping_paper_area).sum()
 }
@@ -38,6 +40,8 @@ fn ribbon_area(dimensions: &str) -> i32 {
     panic!()
 }
 
+legacy_solver!(2015, 2);
+
 #[cfg(test)]
 mod tests {
     use crate::aoc2015::day02::{solve_part_one, solve_part_two};
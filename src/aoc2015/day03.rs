@@ -1,3 +1,5 @@
+use crate::utils::v2::legacy::legacy_solver;
+
 #[allow(unused_imports)]
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
@@ -110,6 +112,8 @@ impl House {
     }
 }
 
+legacy_solver!(2015, 3);
+
 #[cfg(test)]
 mod tests {
     use crate::aoc2015::day03::{solve_part_one, solve_part_two};
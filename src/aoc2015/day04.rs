@@ -1,5 +1,7 @@
 use md5;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     (0..u32::MAX)
         .map(|i| md5::compute(format!("{}{}", input, i)))
@@ -20,6 +22,8 @@ pub fn solve_part_two(input: &String) -> i32 {
         .0 as i32
 }
 
+legacy_solver!(2015, 4);
+
 #[cfg(test)]
 mod tests {
     use crate::aoc2015::day04::solve_part_one;
@@ -1,5 +1,7 @@
 use std::ops::Not;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     input.lines().filter(is_nice_string_one).count() as i32
 }
@@ -66,6 +68,8 @@ fn is_nice_string_two(s: &&str) -> bool {
     fulfils_pair_condition && fulfils_repetition_condition
 }
 
+legacy_solver!(2015, 5);
+
 #[cfg(test)]
 mod tests {
     use super::{solve_part_one, solve_part_two};
@@ -1,6 +1,8 @@
 use regex::Regex;
 use std::cmp::{max, min};
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     let mut grid = Grid::new();
 
@@ -108,6 +110,8 @@ pub fn solve_part_two(input: &String) -> i32 {
     grid.count_total_brightness()
 }
 
+legacy_solver!(2015, 6);
+
 #[cfg(test)]
 mod tests {
     use super::{solve_part_one, solve_part_two};
@@ -4,6 +4,8 @@ use std::fmt::{Debug, Formatter};
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 struct Operation<'a> {
     // optional input
     io: Option<&'a str>,
@@ -122,6 +124,8 @@ pub fn solve_part_two(input: &String) -> u16 {
     solve_part_one(&input)
 }
 
+legacy_solver!(2015, 7);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
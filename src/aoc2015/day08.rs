@@ -1,3 +1,5 @@
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     input.lines().map(count_skipped_chars).sum()
 }
@@ -34,3 +36,5 @@ pub fn solve_part_two(input: &String) -> i32 {
 }
 
 // no tests written for 2015.08 because escaped strings are a pain to type out correctly
+
+legacy_solver!(2015, 8);
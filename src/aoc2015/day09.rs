@@ -4,6 +4,8 @@ use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     lazy_static! {
         static ref RE: Regex = Regex::new(r"(?m)^(\w+) to (\w+) = (\d+)$").unwrap();
@@ -82,6 +84,8 @@ pub fn solve_part_two(input: &String) -> i32 {
         .unwrap()
 }
 
+legacy_solver!(2015, 9);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
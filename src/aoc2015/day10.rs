@@ -2,6 +2,8 @@ use std::iter;
 
 use itertools::Itertools;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     let input = input.lines().next().unwrap().to_string();
     rounds(input, 40).len() as i32
@@ -35,6 +37,8 @@ fn rounds(s: String, n: usize) -> String {
         .unwrap()
 }
 
+legacy_solver!(2015, 10);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
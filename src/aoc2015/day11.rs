@@ -2,6 +2,8 @@ use std::iter;
 
 use itertools::Itertools;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> String {
     let initial_password = input.lines().next().unwrap().to_string();
     next_valid_password(initial_password)
@@ -66,6 +68,8 @@ fn increment_password(pwd: &String) -> String {
     String::from_utf8(chars).unwrap()
 }
 
+legacy_solver!(2015, 11);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -1,6 +1,8 @@
 use json::JsonValue;
 use regex::Regex;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     let re: Regex = Regex::new(r"(-?\d+)").unwrap();
     re.captures_iter(input)
@@ -28,6 +30,8 @@ fn count(data: &JsonValue) -> i32 {
     };
 }
 
+legacy_solver!(2015, 12);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -3,6 +3,8 @@ use std::collections::{HashMap, HashSet};
 use itertools::Itertools;
 use regex::Regex;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     let relations = parse_input(input);
     let people = relations
@@ -73,6 +75,8 @@ fn best_happiness(people: HashSet<&str>, relations: HashMap<(&str, &str), i32>)
         .unwrap()
 }
 
+legacy_solver!(2015, 13);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
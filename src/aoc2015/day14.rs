@@ -4,6 +4,8 @@ use std::collections::HashMap;
 use itertools::Itertools;
 use regex::Regex;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     input
         .lines()
@@ -74,6 +76,8 @@ impl Reindeer {
     }
 }
 
+legacy_solver!(2015, 14);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -5,6 +5,8 @@ use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::{Captures, Regex};
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i64 {
     input
         .lines()
@@ -98,6 +100,8 @@ impl Ingredient {
     }
 }
 
+legacy_solver!(2015, 15);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use itertools::Itertools;
 use regex::Regex;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     Regex::new(r"Sue (\d+): (\w+): (\d+), (\w+): (\d+), (\w+): (\d+)")
         .unwrap()
@@ -85,3 +87,5 @@ pub fn solve_part_two(input: &String) -> i32 {
         .next()
         .unwrap()
 }
+
+legacy_solver!(2015, 16);
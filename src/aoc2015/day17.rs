@@ -1,5 +1,7 @@
 use itertools::Itertools;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> usize {
     input
         .lines()
@@ -28,3 +30,5 @@ pub fn solve_part_two(input: &String) -> usize {
         .filter(|containers| containers.len() == min_containers)
         .count()
 }
+
+legacy_solver!(2015, 17);
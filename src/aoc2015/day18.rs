@@ -3,6 +3,8 @@ use std::str::FromStr;
 
 use itertools::Itertools;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> usize {
     let initial = input.parse::<Grid>().unwrap();
     (0..100)
@@ -115,6 +117,8 @@ impl Grid {
     }
 }
 
+legacy_solver!(2015, 18);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
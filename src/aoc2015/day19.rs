@@ -5,6 +5,8 @@ use itertools::Itertools;
 use priority_queue::{DoublePriorityQueue, PriorityQueue};
 use regex::{Regex, Replacer};
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> usize {
     let (replacements, molecule) = parse_input(input);
     molecule.all_replacements(&replacements).len()
@@ -140,6 +142,8 @@ impl Molecule {
     }
 }
 
+legacy_solver!(2015, 19);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
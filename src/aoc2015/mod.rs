@@ -1,8 +1,7 @@
 #![allow(dead_code, unused_variables, unused_imports)]
 #![allow(unstable_name_collisions)]
 
-use crate::utils::io;
-use crate::utils::io::read;
+use crate::utils::v2::registry::register_solvers;
 
 mod day01;
 mod day02;
@@ -23,3 +22,8 @@ mod day16;
 mod day17;
 mod day18;
 mod day19;
+
+register_solvers!(
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    day14, day15, day16, day17, day18, day19
+);
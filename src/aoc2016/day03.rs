@@ -2,6 +2,8 @@ use std::str::FromStr;
 
 use itertools::Itertools;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 struct Triangle(i32, i32, i32);
 
 impl FromStr for Triangle {
@@ -51,3 +53,5 @@ pub fn solve_part_two(input: String) -> usize {
         .filter(|triangle| triangle.is_valid())
         .count()
 }
+
+legacy_solver!(2016, 3);
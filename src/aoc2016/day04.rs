@@ -3,6 +3,8 @@ use std::str::FromStr;
 use itertools::Itertools;
 use once_cell_regex::regex;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 struct Room {
     name: String,
     id: i32,
@@ -76,6 +78,8 @@ pub fn solve_part_two(input: String) -> i32 {
         .unwrap()
 }
 
+legacy_solver!(2016, 4);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -2,7 +2,9 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 
-#[allow(dead_code)] // OldSolver takes > 60s
+use crate::utils::v2::legacy::legacy_solver;
+
+// Takes > 60s.
 pub fn solve_part_one(input: String) -> String {
     let input = input.trim();
     (0..)
@@ -14,7 +16,7 @@ pub fn solve_part_one(input: String) -> String {
         .collect()
 }
 
-#[allow(dead_code)] // OldSolver takes > 60s
+// Takes > 60s.
 pub fn solve_part_two(input: String) -> String {
     let input = input.trim();
     let mut password = HashMap::new();
@@ -44,6 +46,8 @@ pub fn solve_part_two(input: String) -> String {
     password.into_iter().sorted().map(|(_, val)| val).collect()
 }
 
+legacy_solver!(2016, 5);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
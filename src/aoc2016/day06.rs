@@ -1,5 +1,7 @@
 use itertools::Itertools;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: String) -> String {
     input
         .lines()
@@ -42,6 +44,8 @@ pub fn solve_part_two(input: String) -> String {
         .collect() // concatenate the least common letter from each column
 }
 
+legacy_solver!(2016, 6);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -3,6 +3,8 @@ use std::{collections::HashSet, str::FromStr};
 use itertools::Itertools;
 use once_cell_regex::regex;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 struct Address {
     supernets: Vec<String>, // the characters outside brackets []
     hypernets: Vec<String>, // the characters inside brackets []
@@ -96,6 +98,8 @@ pub fn solve_part_two(input: String) -> usize {
         .count()
 }
 
+legacy_solver!(2016, 7);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -2,7 +2,7 @@ use std::{fmt::Display, str::FromStr};
 
 use once_cell_regex::regex;
 
-use crate::utils::aoc;
+use crate::utils::{grid::Grid, v2::solver};
 
 struct Rectangle {
     width: usize,
@@ -77,6 +77,13 @@ impl Screen {
         }
     }
 
+    fn into_grid(self) -> Grid<char> {
+        let rows = self
+            .grid
+            .map(|row| row.map(|lit| if lit { '#' } else { ' ' }).to_vec());
+        Grid::new(rows.to_vec())
+    }
+
     fn count_lit(self) -> usize {
         self.grid.into_iter().flat_map(|x| x).filter(|x| *x).count()
     }
@@ -111,11 +118,11 @@ impl Display for Screen {
     }
 }
 
-pub struct OldSolver;
+pub struct Solver;
 
-impl aoc::OldSolver<2016, 8> for OldSolver {
+impl solver::RawSolver<2016, 8> for Solver {
     type Part1 = usize;
-    type Part2 = String;
+    type Part2 = Grid<char>;
 
     fn solve_part_one(&self, input: &str) -> Self::Part1 {
         let mut screen = Screen {
@@ -138,6 +145,6 @@ impl aoc::OldSolver<2016, 8> for OldSolver {
             .filter_map(|line| line.parse::<Operation>().ok())
             .for_each(|op| screen.exec(op));
 
-        format!("\n{}", screen)
+        screen.into_grid()
     }
 }
@@ -3,7 +3,7 @@ use std::{collections::BTreeMap, vec};
 use itertools::Itertools;
 use once_cell_regex::regex;
 
-use crate::utils::aoc;
+use crate::utils::v2::solver;
 
 struct Marker {
     // in the format (<grab>x<repeat>)
@@ -83,9 +83,9 @@ fn length_recursive_descent(
     children_len + intermarker_len + trailing_len
 }
 
-pub struct OldSolver;
+pub struct Solver;
 
-impl aoc::OldSolver<2016, 9> for OldSolver {
+impl solver::RawSolver<2016, 9> for Solver {
     type Part1 = usize;
     type Part2 = usize;
 
@@ -125,7 +125,7 @@ impl aoc::OldSolver<2016, 9> for OldSolver {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::aoc;
+    use crate::utils::v2::solver::RawSolver;
     use rstest::rstest;
 
     #[rstest]
@@ -136,10 +136,7 @@ mod tests {
     #[case("(6x1)(1x3)A".to_string(), 6)]
     #[case("X(8x2)(3x3)ABCY".to_string(), 18)]
     fn test_solve_part_one(#[case] input: String, #[case] expected: usize) {
-        assert_eq!(
-            aoc::OldSolver::solve_part_one(&super::OldSolver, &input),
-            expected
-        )
+        assert_eq!(super::Solver.solve_part_one(&input), expected)
     }
 
     #[rstest]
@@ -157,9 +154,6 @@ mod tests {
         242374
     )]
     fn test_solve_part_two(#[case] input: String, #[case] expected: usize) {
-        assert_eq!(
-            aoc::OldSolver::solve_part_two(&super::OldSolver, &input),
-            expected
-        )
+        assert_eq!(super::Solver.solve_part_two(&input), expected)
     }
 }
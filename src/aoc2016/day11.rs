@@ -8,7 +8,7 @@ use std::{
 use itertools::Itertools;
 use once_cell_regex::regex;
 
-use crate::utils::aoc;
+use crate::utils::v2::solver;
 
 #[derive(Clone, Hash, PartialEq, Eq)]
 enum Item {
@@ -247,9 +247,9 @@ impl State {
     }
 }
 
-pub struct OldSolver;
+pub struct Solver;
 
-impl aoc::OldSolver<2016, 11> for OldSolver {
+impl solver::RawSolver<2016, 11> for Solver {
     type Part1 = usize;
     type Part2 = usize;
 
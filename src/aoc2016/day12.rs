@@ -2,7 +2,7 @@ use std::{collections::HashMap, str::FromStr};
 
 use itertools::Itertools;
 
-use crate::utils::aoc;
+use crate::utils::v2::solver;
 
 type Register = char;
 
@@ -47,9 +47,9 @@ impl FromStr for Instruction {
     }
 }
 
-pub struct OldSolver;
+pub struct Solver;
 
-impl OldSolver {
+impl Solver {
     fn exec(instructions: Vec<Instruction>, mut registers: HashMap<char, i32>) -> i32 {
         let mut pc = 0;
         while pc < (instructions.len() as i32) {
@@ -89,7 +89,7 @@ impl OldSolver {
     }
 }
 
-impl aoc::OldSolver<2016, 12> for OldSolver {
+impl solver::RawSolver<2016, 12> for Solver {
     type Part1 = i32;
     type Part2 = i32;
 
@@ -103,7 +103,7 @@ impl aoc::OldSolver<2016, 12> for OldSolver {
         for reg in "abcd".chars() {
             registers.insert(reg, 0);
         }
-        OldSolver::exec(instructions, registers)
+        Solver::exec(instructions, registers)
     }
 
     fn solve_part_two(&self, input: &str) -> Self::Part2 {
@@ -117,6 +117,6 @@ impl aoc::OldSolver<2016, 12> for OldSolver {
             registers.insert(reg, 0);
         }
         registers.insert('c', 1);
-        OldSolver::exec(instructions, registers)
+        Solver::exec(instructions, registers)
     }
 }
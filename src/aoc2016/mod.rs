@@ -1,5 +1,6 @@
 #![allow(dead_code, unused_variables, unused_imports)]
-use crate::utils::{aoc::OldSolver, io};
+
+use crate::utils::v2::registry::register_solvers;
 
 mod day03;
 mod day04;
@@ -11,3 +12,5 @@ mod day09;
 mod day10;
 mod day11;
 mod day12;
+
+register_solvers!(day03, day04, day05, day06, day07, day08, day09, day10, day11, day12);
@@ -5,7 +5,7 @@ use crate::utils::v2::{
 
 pub struct Solver;
 
-impl solver::Solver<2017, 3> for Solver {
+impl solver::RawSolver<2017, 3> for Solver {
     type Part1 = u32;
     type Part2 = u32;
 
@@ -87,7 +87,7 @@ impl SparseGrid<u32> {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::v2::solver::Solver;
+    use crate::utils::v2::solver::RawSolver;
     use rstest::rstest;
 
     #[rstest]
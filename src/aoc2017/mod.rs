@@ -1 +1,5 @@
+use crate::utils::v2::registry::register_solvers;
+
 pub mod day03;
+
+register_solvers!(day03);
@@ -1,5 +1,7 @@
 use itertools::Itertools;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> usize {
     input
         .lines()
@@ -18,6 +20,8 @@ pub fn solve_part_two(input: &String) -> usize {
         .count()
 }
 
+legacy_solver!(2021, 1);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
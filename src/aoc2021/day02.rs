@@ -1,5 +1,7 @@
 use regex::Regex;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     let re = Regex::new(r"(up|down|forward) (\d+)").unwrap();
     let (horizontal, depth) =
@@ -34,6 +36,8 @@ pub fn solve_part_two(input: &String) -> i32 {
     horizontal * depth
 }
 
+legacy_solver!(2021, 2);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -1,5 +1,7 @@
 use itertools::Itertools;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     let (gamma, epsilon): (Vec<char>, Vec<char>) = frequencies(input)
         .iter()
@@ -91,6 +93,8 @@ fn bit_criteria(o2: &Vec<&String>, co2: &Vec<&String>, index: usize) -> (String,
     (most_common.to_string(), least_common.to_string())
 }
 
+legacy_solver!(2021, 3);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -1,5 +1,7 @@
 use itertools::Itertools;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 static BOARD_SIZE: usize = 5;
 
 pub fn solve_part_one(input: &String) -> i32 {
@@ -122,6 +124,8 @@ fn parse_input(input: &String) -> (Vec<i32>, Vec<Board>) {
     (numbers, boards)
 }
 
+legacy_solver!(2021, 4);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -4,6 +4,8 @@ use itertools::Itertools;
 use itertools::MinMaxResult::MinMax;
 use regex::Regex;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> usize {
     let hv_lines = Regex::new(r"(?m)(\d+),(\d+) -> (\d+),(\d+)")
         .unwrap()
@@ -131,6 +133,8 @@ pub fn solve_part_two(input: &String) -> usize {
         .count()
 }
 
+legacy_solver!(2021, 5);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
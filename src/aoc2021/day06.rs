@@ -6,6 +6,8 @@ use itertools::Itertools;
 use itertools::MinMaxResult::MinMax;
 use regex::Regex;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i64 {
     fish_after_n_days(input, 80)
 }
@@ -41,6 +43,8 @@ pub fn solve_part_two(input: &String) -> i64 {
     fish_after_n_days(input, 256)
 }
 
+legacy_solver!(2021, 6);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
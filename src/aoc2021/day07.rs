@@ -1,5 +1,7 @@
 use itertools::Itertools;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     let crabs = input
         .split(',')
@@ -38,6 +40,8 @@ pub fn solve_part_two(input: &String) -> i32 {
         .unwrap()
 }
 
+legacy_solver!(2021, 7);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -3,6 +3,8 @@ use std::str::FromStr;
 
 use itertools::Itertools;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> usize {
     input
         .lines()
@@ -124,6 +126,8 @@ impl Entry {
     }
 }
 
+legacy_solver!(2021, 8);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -4,6 +4,8 @@ use std::str::FromStr;
 use itertools::Itertools;
 use ndarray::Array2;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     let cave = input.parse::<Cave>().unwrap();
     (0..cave.height)
@@ -118,6 +120,8 @@ impl Cave {
     }
 }
 
+legacy_solver!(2021, 9);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -3,6 +3,8 @@ use itertools::Itertools;
 
 use self::PairedDelimiter::{Close, Open};
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i64 {
     input.lines().map(self::corrupt).sum()
 }
@@ -133,6 +135,8 @@ fn complete(s: &str) -> i64 {
         })
 }
 
+legacy_solver!(2021, 10);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -5,6 +5,8 @@ use itertools::Itertools;
 
 use crate::utils::coordinates::dim_2::Coordinates;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> usize {
     let mut cavern = Cavern::from_str(input).unwrap();
     let mut flashes = 0;
@@ -98,6 +100,8 @@ impl Cavern {
     }
 }
 
+legacy_solver!(2021, 11);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
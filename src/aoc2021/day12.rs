@@ -3,6 +3,8 @@ use petgraph::Undirected;
 use serde_scan::scan;
 use std::collections::HashMap;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum Cave<'a> {
     Big(&'a str),
@@ -113,6 +115,8 @@ pub fn solve_part_two(input: &String) -> i32 {
     paths
 }
 
+legacy_solver!(2021, 12);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -6,6 +6,8 @@ use serde_scan::scan;
 
 use crate::utils::coordinates::dim_2::Coordinates;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 #[derive(Copy, Clone)]
 struct Fold {
     axis: char,
@@ -118,6 +120,8 @@ pub fn solve_part_two(input: &String) -> String {
     sb
 }
 
+legacy_solver!(2021, 13);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -5,6 +5,8 @@ use itertools::Itertools;
 use regex::Regex;
 use serde_scan::scan;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> usize {
     let mut initial = input.lines().next().unwrap().chars().collect_vec();
     let rules = Regex::new(r"(\w)(\w) -> (\w)")
@@ -120,6 +122,8 @@ pub fn solve_part_two(input: &String) -> u64 {
     }
 }
 
+legacy_solver!(2021, 14);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
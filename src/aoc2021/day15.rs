@@ -3,6 +3,8 @@ use petgraph::algo::dijkstra;
 use petgraph::prelude::DiGraphMap;
 use std::collections::HashMap;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> u32 {
     let height = input.lines().count() as i32;
     let width = input.lines().next().unwrap().chars().count() as i32;
@@ -52,6 +54,8 @@ pub fn solve_part_two(input: &String) -> u32 {
     solve_part_one(&sb)
 }
 
+legacy_solver!(2021, 15);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -5,6 +5,8 @@ use serde_scan::scan;
 
 use crate::utils::coordinates::dim_2::Coordinates;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> i32 {
     let target_area = input.parse::<TargetArea>().unwrap();
     (0..=target_area.x_max)
@@ -121,6 +123,8 @@ impl Probe {
     }
 }
 
+legacy_solver!(2021, 17);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -4,6 +4,8 @@ use std::str::FromStr;
 use itertools::Itertools;
 use num_integer::Integer;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> u32 {
     let snailfishes = input
         .lines()
@@ -157,6 +159,8 @@ impl SnailfishNumbers {
     }
 }
 
+legacy_solver!(2021, 18);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
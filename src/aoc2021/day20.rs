@@ -6,6 +6,8 @@ use itertools::Itertools;
 
 use crate::utils::coordinates::dim_2::Coordinates;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> usize {
     input
         .trim()
@@ -148,6 +150,8 @@ impl Image {
     }
 }
 
+legacy_solver!(2021, 20);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -6,6 +6,8 @@ use itertools::Itertools;
 
 use crate::utils::coordinates::dim_2::Coordinates;
 
+use crate::utils::v2::legacy::legacy_solver;
+
 pub fn solve_part_one(input: &String) -> usize {
     let mut seafloor = input.parse::<Seafloor>().unwrap();
     let mut steps = 0;
@@ -153,6 +155,8 @@ impl Seafloor {
     }
 }
 
+legacy_solver!(2021, 25);
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -24,7 +24,7 @@ impl FromStr for Elf {
 
 pub struct Solver;
 
-impl solver::Solver<2022, 1> for Solver {
+impl solver::RawSolver<2022, 1> for Solver {
     type Part1 = i32;
     type Part2 = i32;
 
@@ -92,7 +92,7 @@ impl Outcome {
 
 pub struct Solver;
 
-impl solver::Solver<2022, 2> for Solver {
+impl solver::RawSolver<2022, 2> for Solver {
     type Part1 = i32;
     type Part2 = i32;
 
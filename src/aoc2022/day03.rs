@@ -26,7 +26,7 @@ impl Solver {
     }
 }
 
-impl solver::Solver<2022, 3> for Solver {
+impl solver::RawSolver<2022, 3> for Solver {
     type Part1 = usize;
     type Part2 = usize;
 
@@ -4,7 +4,7 @@ use scan_fmt::scan_fmt;
 
 pub struct Solver;
 
-impl solver::Solver<2022, 4> for Solver {
+impl solver::RawSolver<2022, 4> for Solver {
     type Part1 = usize;
     type Part2 = usize;
 
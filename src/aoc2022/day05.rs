@@ -48,7 +48,7 @@ impl Solver {
     }
 }
 
-impl solver::Solver<2022, 5> for Solver {
+impl solver::RawSolver<2022, 5> for Solver {
     type Part1 = String;
     type Part2 = String;
 
@@ -17,7 +17,7 @@ impl Solver {
     }
 }
 
-impl solver::Solver<2022, 6> for Solver {
+impl solver::RawSolver<2022, 6> for Solver {
     type Part1 = usize;
     type Part2 = usize;
 
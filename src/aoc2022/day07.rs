@@ -53,7 +53,7 @@ impl Solver {
     }
 }
 
-impl solver::Solver<2022, 7> for Solver {
+impl solver::RawSolver<2022, 7> for Solver {
     type Part1 = i32;
     type Part2 = i32;
 
@@ -15,7 +15,7 @@ impl Solver {
 }
 
 // perf: brute forced
-impl solver::Solver<2022, 8> for Solver {
+impl solver::RawSolver<2022, 8> for Solver {
     type Part1 = usize;
     type Part2 = usize;
 
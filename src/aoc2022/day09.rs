@@ -26,7 +26,7 @@ impl FromStr for Vector {
 
 pub struct Solver;
 
-impl solver::Solver<2022, 9> for Solver {
+impl solver::RawSolver<2022, 9> for Solver {
     type Part1 = usize;
     type Part2 = usize;
 
@@ -19,9 +19,9 @@ impl Solver {
     }
 }
 
-impl solver::Solver<2022, 10> for Solver {
+impl solver::RawSolver<2022, 10> for Solver {
     type Part1 = i32;
-    type Part2 = String;
+    type Part2 = Grid<char>;
 
     fn solve_part_one(&self, input: &str) -> Self::Part1 {
         Solver::signal_strengths(input)
@@ -35,7 +35,7 @@ impl solver::Solver<2022, 10> for Solver {
 
     fn solve_part_two(&self, input: &str) -> Self::Part2 {
         let xs = Solver::signal_strengths(input);
-        let grid = Grid::from_generator(
+        Grid::from_generator(
             6,
             40,
             |c| {
@@ -49,7 +49,6 @@ impl solver::Solver<2022, 10> for Solver {
                 Some(pixel)
             },
             ' ',
-        );
-        utils::ocr(&grid)
+        )
     }
 }
@@ -82,7 +82,7 @@ impl Solver {
     }
 }
 
-impl solver::Solver<2022, 11> for Solver {
+impl solver::RawSolver<2022, 11> for Solver {
     type Part1 = usize;
     type Part2 = usize;
 
@@ -51,7 +51,7 @@ impl Solver {
     }
 }
 
-impl solver::Solver<2022, 12> for Solver {
+impl solver::RawSolver<2022, 12> for Solver {
     type Part1 = usize;
     type Part2 = usize;
 
@@ -108,9 +108,9 @@ impl Solver {
     }
 }
 
-impl solver::Solver<2022, 13> for Solver {
+impl solver::RawSolver<2022, 13> for Solver {
     type Part1 = usize;
-    type Part2 = usize;
+    type Part2 = Option<usize>;
 
     fn solve_part_one(&self, input: &str) -> Self::Part1 {
         let mut ans = 0;
@@ -126,6 +126,6 @@ impl solver::Solver<2022, 13> for Solver {
     }
 
     fn solve_part_two(&self, _input: &str) -> Self::Part2 {
-        0
+        None
     }
 }
@@ -161,9 +161,9 @@ impl Solver {
     }
 }
 
-impl solver::Solver<2022, 17> for Solver {
+impl solver::RawSolver<2022, 17> for Solver {
     type Part1 = i32;
-    type Part2 = String;
+    type Part2 = Result<i64, &'static str>;
 
     fn solve_part_one(&self, input: &str) -> Self::Part1 {
         let jets = input
@@ -209,6 +209,6 @@ impl solver::Solver<2022, 17> for Solver {
     }
 
     fn solve_part_two(&self, _input: &str) -> Self::Part2 {
-        "[not done programmatically - find the cycle delta manually]".to_owned()
+        Err("not done programmatically - find the cycle delta manually")
     }
 }
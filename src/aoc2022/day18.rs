@@ -81,7 +81,7 @@ impl Solver {
     }
 }
 
-impl solver::Solver<2022, 18> for Solver {
+impl solver::RawSolver<2022, 18> for Solver {
     type Part1 = usize;
     type Part2 = usize;
 
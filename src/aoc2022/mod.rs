@@ -1,3 +1,5 @@
+use crate::utils::v2::registry::register_solvers;
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -13,3 +15,8 @@ pub mod day12;
 pub mod day13;
 pub mod day17;
 pub mod day18;
+
+register_solvers!(
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    day17, day18
+);
@@ -22,7 +22,7 @@ impl Solver {
     }
 }
 
-impl solver::Solver<2024, 2> for Solver {
+impl solver::RawSolver<2024, 2> for Solver {
     type Part1 = usize;
     type Part2 = usize;
 
@@ -52,7 +52,7 @@ impl solver::Solver<2024, 2> for Solver {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::v2::solver::Solver;
+    use crate::utils::v2::solver::RawSolver;
     use rstest::rstest;
 
     #[rstest]
@@ -16,7 +16,7 @@ impl Solver {
     }
 }
 
-impl solver::Solver<2024, 3> for Solver {
+impl solver::RawSolver<2024, 3> for Solver {
     type Part1 = u64;
     type Part2 = u64;
 
@@ -33,7 +33,7 @@ impl solver::Solver<2024, 3> for Solver {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::v2::solver::Solver;
+    use crate::utils::v2::solver::RawSolver;
     use rstest::rstest;
 
     #[rstest]
@@ -16,7 +16,7 @@ const D: [(i32, i32); 8] = [
 
 pub struct Solver;
 
-impl solver::Solver<2024, 4> for Solver {
+impl solver::RawSolver<2024, 4> for Solver {
     type Part1 = i32;
     type Part2 = i32;
 
@@ -78,7 +78,7 @@ impl solver::Solver<2024, 4> for Solver {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::v2::solver::Solver;
+    use crate::utils::v2::solver::RawSolver;
     use rstest::rstest;
 
     #[rstest]
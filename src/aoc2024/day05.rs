@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use anyhow::{ensure, Context, Result};
 use itertools::Itertools;
 
 use crate::utils::v2::{parser::get_all_ints_signed, solver};
@@ -10,22 +11,6 @@ type Constraints = HashMap<i64, Vec<i64>>;
 type Input = (Constraints, Updates);
 
 impl Solver {
-    fn parse_input(&self, input: &str) -> Input {
-        let (constraints, updates) = input
-            .split("\n\n")
-            .map(|section| section.lines().map(get_all_ints_signed).collect_vec())
-            .collect_tuple()
-            .unwrap();
-
-        let constraints = constraints
-            .into_iter()
-            .map(|v| (v[0], v[1]))
-            .into_group_map();
-
-        assert!(updates.iter().all(|v| v.len() % 2 == 1));
-        (constraints, updates)
-    }
-
     fn satisfies_constraints(&self, constraints: &Constraints, update: &Update) -> bool {
         for (i, r) in update.iter().enumerate() {
             if constraints.get(r).is_some() {
@@ -43,27 +28,45 @@ impl Solver {
 pub struct Solver;
 
 impl solver::Solver<2024, 5> for Solver {
+    type Input = Input;
     type Part1 = i64;
     type Part2 = i64;
 
-    fn solve_part_one(&self, input: &str) -> Self::Part1 {
-        let (constraints, updates) = self.parse_input(input);
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let (constraints, updates) = input
+            .split("\n\n")
+            .map(|section| section.lines().map(get_all_ints_signed).collect_vec())
+            .collect_tuple()
+            .context("expected page ordering rules and updates")?;
 
-        updates
+        let constraints = constraints
             .into_iter()
-            .filter(|update| self.satisfies_constraints(&constraints, update))
+            .map(|v| (v[0], v[1]))
+            .into_group_map();
+
+        ensure!(
+            updates.iter().all(|v| v.len() % 2 == 1),
+            "expected an odd number of pages in every update"
+        );
+        Ok((constraints, updates))
+    }
+
+    fn solve_part_one(&self, (constraints, updates): &Self::Input) -> Self::Part1 {
+        updates
+            .iter()
+            .filter(|update| self.satisfies_constraints(constraints, update))
             .map(|update| update[update.len() / 2])
             .sum()
     }
 
-    fn solve_part_two(&self, input: &str) -> Self::Part2 {
-        let (constraints, updates) = self.parse_input(input);
+    fn solve_part_two(&self, (constraints, updates): &Self::Input) -> Self::Part2 {
         let mut result = 0;
-        for mut update in updates {
-            if self.satisfies_constraints(&constraints, &update) {
+        for update in updates {
+            if self.satisfies_constraints(constraints, update) {
                 continue;
             }
-            while !self.satisfies_constraints(&constraints, &update) {
+            let mut update = update.clone();
+            while !self.satisfies_constraints(constraints, &update) {
                 for i in 0..update.len() {
                     for j in i..update.len() {
                         if let Some(c) = constraints.get(&update[j]) {
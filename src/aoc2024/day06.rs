@@ -1,47 +1,32 @@
 use std::collections::HashSet;
 
+use anyhow::{Context, Result};
+
 use crate::utils::v2::grid::{DenseGrid, Grid, GridFind};
 use crate::utils::v2::solver;
+use crate::utils::v2::visualize::{self, Frame};
 
 pub struct Solver;
 
-impl Solver {
-    fn parse_input(&self, input: &str) -> (DenseGrid<char, i32>, HashSet<(i32, i32)>, (i32, i32)) {
-        let mut grid = DenseGrid::try_from(input).unwrap();
-        let mut visited = HashSet::new();
-
-        let initial = grid.find(&'^').unwrap();
-        let (mut x, mut y) = initial;
-        grid.set(x, y, '.');
-        visited.insert((x, y));
-
-        let (mut dx, mut dy) = (-1, 0);
-        while let Some(&c) = grid.get(x as i32 + dx, y as i32 + dy) {
-            if c == '#' {
-                (dx, dy) = (dy, -dx);
-            } else {
-                visited.insert((x, y));
-                x = x + dx;
-                y = y + dy;
-            }
-        }
-
-        visited.insert((x, y));
-        (grid, visited, initial)
-    }
-}
-
 impl solver::Solver<2024, 6> for Solver {
+    type Input = (DenseGrid<char, i32>, (i32, i32));
     type Part1 = usize;
     type Part2 = i32;
 
-    fn solve_part_one(&self, input: &str) -> Self::Part1 {
-        let (_, visited, _) = self.parse_input(input);
-        visited.len()
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let mut grid = DenseGrid::try_from(input)?;
+        let initial = grid.find(&'^').context("no guard in the map")?;
+        grid.set(initial.0, initial.1, '.');
+        Ok((grid, initial))
     }
 
-    fn solve_part_two(&self, input: &str) -> Self::Part2 {
-        let (mut grid, mut visited, initial) = self.parse_input(input);
+    fn solve_part_one(&self, (grid, initial): &Self::Input) -> Self::Part1 {
+        walk(grid, *initial, true).len()
+    }
+
+    fn solve_part_two(&self, input: &Self::Input) -> Self::Part2 {
+        let (mut grid, initial) = input.clone();
+        let mut visited = walk(&grid, initial, false);
         visited.remove(&initial);
         let mut result = 0;
         let abort_threshold = visited.len() * 2; // FIXME: check for infinite loops properly
@@ -75,3 +60,42 @@ impl solver::Solver<2024, 6> for Solver {
         result
     }
 }
+
+/// Walks the guard from `initial` until they leave the map, returning every position visited.
+/// Each step is recorded as a frame if `record` is set and a visualization was requested.
+fn walk(grid: &DenseGrid<char, i32>, initial: (i32, i32), record: bool) -> HashSet<(i32, i32)> {
+    let mut visited = HashSet::new();
+    let (mut x, mut y) = initial;
+    visited.insert((x, y));
+
+    let (mut dx, mut dy) = (-1, 0);
+    while let Some(&c) = grid.get(x + dx, y + dy) {
+        if c == '#' {
+            (dx, dy) = (dy, -dx);
+        } else {
+            visited.insert((x, y));
+            x += dx;
+            y += dy;
+        }
+        if record {
+            visualize::record(|| {
+                let guard = match (dx, dy) {
+                    (-1, 0) => '^',
+                    (0, 1) => '>',
+                    (1, 0) => 'v',
+                    _ => '<',
+                };
+                let frame = visited.iter().fold(Frame::from(grid), |frame, &(x, y)| {
+                    frame.set(x as usize, y as usize, 'X')
+                });
+                frame
+                    .set(x as usize, y as usize, guard)
+                    .highlight(x as usize, y as usize)
+                    .caption(format!("{} positions visited", visited.len()))
+            });
+        }
+    }
+
+    visited.insert((x, y));
+    visited
+}
@@ -1,4 +1,4 @@
-use advent_of_code::utils::v2::parser::get_all_ints_unsigned;
+use crate::utils::v2::parser::get_all_ints_unsigned;
 use itertools::Itertools;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
@@ -53,7 +53,7 @@ impl Solver {
     }
 }
 
-impl solver::Solver<2024, 7> for Solver {
+impl solver::RawSolver<2024, 7> for Solver {
     type Part1 = u64;
     type Part2 = u64;
 
@@ -92,7 +92,7 @@ impl solver::Solver<2024, 7> for Solver {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::v2::solver::Solver;
+    use crate::utils::v2::solver::RawSolver;
     use rstest::rstest;
 
     #[rstest]
@@ -72,7 +72,7 @@ impl Solver {
     }
 }
 
-impl solver::Solver<2024, 8> for Solver {
+impl solver::RawSolver<2024, 8> for Solver {
     type Part1 = usize;
     type Part2 = usize;
 
@@ -1,17 +1,18 @@
 use std::collections::VecDeque;
 
+use anyhow::{ensure, Context, Result};
 use itertools::Itertools;
 
 use crate::utils::v2::solver;
 
-#[derive(Debug)]
-struct Fragment {
+#[derive(Debug, Clone)]
+pub struct Fragment {
     files: VecDeque<File>,
     free_block_size: u8,
 }
 
 #[derive(Debug, Clone)]
-struct File {
+pub struct File {
     id: usize,
     block_size: u8,
     has_moved: bool,
@@ -25,15 +26,23 @@ enum Span {
 
 pub struct Solver;
 
-impl Solver {
-    fn parse_input(&self, input: &str) -> Vec<Fragment> {
+impl solver::Solver<2024, 9> for Solver {
+    type Input = Vec<Fragment>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
         let input = input
             .chars()
-            .map(|c| c as u8 - '0' as u8)
-            .chain([0])
-            .collect_vec();
+            .map(|c| {
+                c.to_digit(10)
+                    .map(|d| d as u8)
+                    .with_context(|| format!("expected a digit, found {c:?}"))
+            })
+            .chain([Ok(0)])
+            .collect::<Result<Vec<_>>>()?;
 
-        assert!(input.len() % 2 == 0);
+        ensure!(input.len() % 2 == 0, "expected an odd number of digits");
         let fragments = input
             .chunks(2)
             .enumerate()
@@ -47,16 +56,11 @@ impl Solver {
             })
             .collect_vec();
 
-        fragments
+        Ok(fragments)
     }
-}
-
-impl solver::Solver<2024, 9> for Solver {
-    type Part1 = usize;
-    type Part2 = usize;
 
-    fn solve_part_one(&self, input: &str) -> Self::Part1 {
-        let mut fragments = self.parse_input(input);
+    fn solve_part_one(&self, input: &Self::Input) -> Self::Part1 {
+        let mut fragments = input.clone();
         let mut target_fragment_idx = 0;
         let mut data_fragment_idx = fragments.len() - 1;
 
@@ -105,11 +109,10 @@ impl solver::Solver<2024, 9> for Solver {
         checksum
     }
 
-    fn solve_part_two(&self, input: &str) -> Self::Part2 {
-        let fragments = self.parse_input(input);
-        let mut fs = fragments
-            .into_iter()
-            .flat_map(move |frag| {
+    fn solve_part_two(&self, input: &Self::Input) -> Self::Part2 {
+        let mut fs = input
+            .iter()
+            .flat_map(|frag| {
                 [
                     Span::File(frag.files[0].clone()),
                     Span::EmptySpace(frag.free_block_size),
@@ -178,7 +181,8 @@ mod tests {
     #[case("2333133121414131402", 1928)]
     fn test_part_one(#[case] input: &str, #[case] expected: usize) {
         let solver = super::Solver;
-        let result = solver.solve_part_one(input);
+        let input = solver.parse(input).unwrap();
+        let result = solver.solve_part_one(&input);
         assert_eq!(result, expected);
     }
 
@@ -186,7 +190,8 @@ mod tests {
     #[case("2333133121414131402", 2858)]
     fn test_part_two(#[case] input: &str, #[case] expected: usize) {
         let solver = super::Solver;
-        let result = solver.solve_part_two(input);
+        let input = solver.parse(input).unwrap();
+        let result = solver.solve_part_two(&input);
         assert_eq!(result, expected);
     }
 }
@@ -6,7 +6,7 @@ use crate::utils::v2::solver;
 
 pub struct Solver;
 
-impl solver::Solver<2024, 10> for Solver {
+impl solver::RawSolver<2024, 10> for Solver {
     type Part1 = usize;
     type Part2 = usize;
 
@@ -49,7 +49,7 @@ impl solver::Solver<2024, 10> for Solver {
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::v2::solver::Solver;
+    use crate::utils::v2::solver::RawSolver;
     use rstest::rstest;
 
     #[rstest]
@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
+use anyhow::Result;
 use itertools::Itertools;
 
 use crate::utils::v2::{
@@ -12,12 +13,14 @@ type Regions = HashMap<(i32, i32), Region>;
 
 pub struct Solver;
 
-impl Solver {
-    pub fn parse_input(&self, input: &str) -> (DenseGrid<char, i32>, Regions) {
+impl solver::Solver<2024, 12> for Solver {
+    type Input = (DenseGrid<char, i32>, Regions);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
         // Pad the char grid with '#' so that we can compute the perimeter and take 2x2 windows later.
-        let grid = DenseGrid::<char, i32>::try_from(input)
-            .unwrap()
-            .pad_into(1, '#');
+        let grid = DenseGrid::<char, i32>::try_from(input)?.pad_into(1, '#');
         let mut visited = HashSet::<(i32, i32)>::new();
         let mut regions = HashMap::new();
 
@@ -43,26 +46,20 @@ impl Solver {
             regions.insert(s, region);
         }
 
-        (grid, regions)
+        Ok((grid, regions))
     }
-}
 
-impl solver::Solver<2024, 12> for Solver {
-    type Part1 = usize;
-    type Part2 = usize;
-
-    fn solve_part_one(&self, input: &str) -> Self::Part1 {
-        let (grid, regions) = self.parse_input(input);
+    fn solve_part_one(&self, (grid, regions): &Self::Input) -> Self::Part1 {
         let mut result = 0;
 
-        for (s, region) in regions {
+        for (&s, region) in regions {
             let v = *grid.get_from_coords(s.into()).unwrap();
             let area = region.len();
 
             // Using the padding trick, '#' counts as a plot which does not belong in the same region.
             let perimeter = region
-                .into_iter()
-                .map(|(x, y)| {
+                .iter()
+                .map(|&(x, y)| {
                     grid.indexed_adjacent_neighbors_iter(x, y)
                         .filter(|(_, &nv)| nv != v)
                         .count()
@@ -74,8 +71,7 @@ impl solver::Solver<2024, 12> for Solver {
         result
     }
 
-    fn solve_part_two(&self, input: &str) -> Self::Part2 {
-        let (grid, regions) = self.parse_input(input);
+    fn solve_part_two(&self, (grid, regions): &Self::Input) -> Self::Part2 {
         let mut result = 0;
 
         // Recolor the regions in the grid so that non-overlapping regions have different letters,
@@ -85,9 +81,11 @@ impl solver::Solver<2024, 12> for Solver {
             .enumerate()
             .flat_map(|(i, (_, region))| region.iter().map(move |(x, y)| ((*x, *y), i + 1)))
             .collect::<HashMap<_, _>>();
-        let grid = grid.indexed_map_into(|(x, y), v| if v == '#' { 0 } else { mapping[&(x, y)] });
+        let grid = grid
+            .clone()
+            .indexed_map_into(|(x, y), v| if v == '#' { 0 } else { mapping[&(x, y)] });
 
-        for (s, region) in regions {
+        for (&s, region) in regions {
             let v = *grid.get_from_coords(s.into()).unwrap();
             let subgrid = grid
                 .to_minimum_spanning_subgrid(&region.iter().copied().collect_vec())
@@ -1,3 +1,5 @@
+use crate::utils::v2::registry::register_solvers;
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
@@ -10,3 +12,7 @@ pub mod day09;
 pub mod day10;
 pub mod day11;
 pub mod day12;
+
+register_solvers!(
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12
+);
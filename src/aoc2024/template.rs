@@ -1,16 +0,0 @@
-use crate::utils::v2::solver;
-
-pub struct Solver;
-
-impl solver::Solver<2024, 1> for Solver {
-    type Part1 = i32;
-    type Part2 = i32;
-
-    fn solve_part_one(&self, input: &str) -> Self::Part1 {
-        0
-    }
-
-    fn solve_part_two(&self, input: &str) -> Self::Part2 {
-        0
-    }
-}
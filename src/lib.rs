@@ -1 +1,123 @@
+mod aoc2015;
+mod aoc2016;
+mod aoc2017;
+mod aoc2021;
+mod aoc2022;
+mod aoc2024;
 pub mod utils;
+
+use std::sync::OnceLock;
+
+use anyhow::{anyhow, Result};
+use utils::v2::registry::Registry;
+
+/// Returns a registry of every solver in this crate.
+pub fn registry() -> Registry {
+    let mut registry = Registry::default();
+    aoc2015::register(&mut registry);
+    aoc2016::register(&mut registry);
+    aoc2017::register(&mut registry);
+    aoc2021::register(&mut registry);
+    aoc2022::register(&mut registry);
+    aoc2024::register(&mut registry);
+    registry
+}
+
+fn shared_registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(registry)
+}
+
+/// Solves one part of a puzzle for the given input, returning the answer as it would be
+/// submitted. Nothing is printed, and nothing is read from or written to the input cache.
+pub fn run(year: u32, day: u32, part: u8, input: &str) -> Result<String> {
+    shared_registry()
+        .get(year, day)
+        .ok_or_else(|| anyhow!("no solver for day {day} of {year}"))?
+        .answer(part, input.trim())
+}
+
+/// Every year with at least one solver, in ascending order.
+pub fn years() -> Vec<u32> {
+    shared_registry().years()
+}
+
+/// Every day of the year which has a solver, in ascending order.
+pub fn days(year: u32) -> Vec<u32> {
+    shared_registry().days(year).collect()
+}
+
+pub fn has_solver(year: u32, day: u32) -> bool {
+    shared_registry().get(year, day).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use rstest::rstest;
+
+    use super::{days, has_solver, registry, run, years};
+
+    #[rstest]
+    #[case(2024, 9, 1, "2333133121414131402\n", "1928")]
+    #[case(2024, 9, 2, "2333133121414131402", "2858")]
+    #[case(2015, 1, 1, "(()(()(", "3")]
+    fn test_run(
+        #[case] year: u32,
+        #[case] day: u32,
+        #[case] part: u8,
+        #[case] input: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(run(year, day, part, input).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(2024, 9, 3)]
+    #[case(2019, 1, 1)]
+    fn test_run_missing(#[case] year: u32, #[case] day: u32, #[case] part: u8) {
+        assert!(run(year, day, part, "2333133121414131402").is_err());
+    }
+
+    #[test]
+    fn test_metadata() {
+        assert!(years().contains(&2024));
+        assert_eq!(days(2024).first(), Some(&1));
+        assert!(has_solver(2024, 9));
+        assert!(!has_solver(2019, 1));
+    }
+
+    /// Registration is listed by hand in each year's `mod.rs`, so check that every day module it
+    /// declares was also added to `register_solvers!`.
+    #[test]
+    fn test_every_day_is_registered() {
+        let registry = registry();
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let mut declared = 0;
+        for entry in fs::read_dir(src).unwrap() {
+            let year_dir = entry.unwrap().path();
+            let name = year_dir.file_name().unwrap().to_string_lossy().into_owned();
+            let Some(year) = name.strip_prefix("aoc").and_then(|y| y.parse::<u32>().ok()) else {
+                continue;
+            };
+            let year_module = fs::read_to_string(year_dir.join("mod.rs")).unwrap();
+            for line in year_module.lines() {
+                let Some(day) = line
+                    .trim_start_matches("pub ")
+                    .strip_prefix("mod day")
+                    .and_then(|d| d.strip_suffix(';'))
+                    .and_then(|d| d.parse::<u32>().ok())
+                else {
+                    continue;
+                };
+                assert!(
+                    registry.get(year, day).is_some(),
+                    "day{day:02} is declared in src/{name}/mod.rs but not registered"
+                );
+                declared += 1;
+            }
+        }
+        assert_eq!(declared, registry.iter().count());
+    }
+}
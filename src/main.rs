@@ -1,18 +1,39 @@
-mod aoc2015;
-mod aoc2016;
-mod aoc2017;
-mod aoc2021;
-mod aoc2022;
-mod aoc2024;
-mod utils;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
 
+use advent_of_code::{
+    registry,
+    utils::v2::{
+        bench::BenchReport,
+        client::Client,
+        config::{Config, Profile},
+        examples,
+        format::{self, Format, Record},
+        leaderboard::{self, Leaderboard},
+        ledger::Ledger,
+        perf::{self, History},
+        progress::{self, Progress},
+        puzzle,
+        registry::DynSolver,
+        scaffold,
+        solver::{PartReport, SolveOptions},
+        submit::{self, Verdict},
+        summary, unlock, verify, visualize, watch,
+    },
+};
+use ansi_term::Colour;
 use chrono::{Datelike, Utc};
-use clap::Parser;
-use utils::v2::solver::Solver;
+use clap::{Parser, Subcommand};
+use json::JsonValue;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
     #[arg(short = 'd', long = "day")]
     day: Option<u32>,
     #[arg(short = 'y', long = "year")]
@@ -21,10 +42,263 @@ struct Args {
     refetch: bool,
     #[arg(short = 'i', long = "input")]
     input_file_extension: Option<String>,
+    /// Read the input from this file instead of `input/`, or from stdin if it is `-`.
+    #[arg(long = "input-path", value_name = "FILE", conflicts_with_all = ["input_file_extension", "all", "year_only", "submit"])]
+    input_path: Option<PathBuf>,
+    /// Solve only this part.
+    #[arg(short = 'p', long = "part", value_parser = clap::value_parser!(u8).range(1..=2), conflicts_with = "bench")]
+    part: Option<u8>,
+    /// List every puzzle which has a solver, then exit.
+    #[arg(short = 'l', long = "list")]
+    list: bool,
+    /// Solve every puzzle of every year, then print a summary.
+    #[arg(short = 'a', long = "all", conflicts_with_all = ["day", "year", "year_only"])]
+    all: bool,
+    /// Solve every puzzle of the year given by -y, then print a summary.
+    #[arg(long = "year-only", requires = "year", conflicts_with = "day")]
+    year_only: bool,
+    /// Submit the answer to the given part after solving it.
+    #[arg(short = 's', long = "submit", value_parser = clap::value_parser!(u8).range(1..=2), conflicts_with = "input_file_extension")]
+    submit: Option<u8>,
+    /// Wait until the puzzle unlocks, then fetch the input and solve it.
+    #[arg(short = 'w', long = "wait")]
+    wait: bool,
+    /// Print the puzzle description instead of solving it.
+    #[arg(long = "read")]
+    read: bool,
+    /// Extract the examples from the puzzle description and check the solver against them.
+    #[arg(long = "check-examples")]
+    check_examples: bool,
+    /// Give up on a part after this many seconds, and move on to the next one.
+    #[arg(short = 't', long = "timeout")]
+    timeout: Option<u64>,
+    /// Time parsing and each part over this many runs instead of solving once.
+    #[arg(short = 'b', long = "bench", value_parser = clap::value_parser!(u32).range(1..), conflicts_with = "submit")]
+    bench: Option<u32>,
+    /// Print the benchmark report as JSON.
+    #[arg(long = "json", requires = "bench")]
+    json: bool,
+    /// Show the timing history of each part, flagging parts which got more than this many percent
+    /// slower than their best time. Filtered by -y and -d if given.
+    #[arg(long = "perf-report", value_name = "PERCENT", num_args = 0..=1, default_missing_value = "10")]
+    perf_report: Option<f64>,
+    /// Solve again whenever the input or example files change, rebuilding first if the solver's
+    /// source changes.
+    #[arg(long = "watch", conflicts_with_all = ["all", "year_only", "submit", "bench", "format", "input_path"])]
+    watch: bool,
+    /// Use the session token and cache directory of this profile from aoc.toml.
+    #[arg(long = "profile", value_name = "NAME")]
+    profile: Option<String>,
+    /// Print one record per part in this format instead of the usual progress, for scripts.
+    #[arg(long = "format", conflicts_with_all = ["bench", "submit"])]
+    format: Option<Format>,
+    /// Play the frames the solver records in the terminal after solving, if it records any.
+    #[arg(long = "visualize", conflicts_with_all = ["all", "year_only", "bench", "watch", "format"])]
+    visualize: bool,
+    /// How many frames to play per second.
+    #[arg(long = "fps", default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..), requires = "visualize")]
+    fps: u32,
+    /// Write the frames to this directory as numbered PPM images instead of playing them.
+    #[arg(long = "ppm", value_name = "DIR", requires = "visualize")]
+    ppm: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Generate the solver for a new day from the template.
+    New {
+        #[arg(short = 'd', long = "day", value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+        #[arg(short = 'y', long = "year")]
+        year: u32,
+    },
+    /// Print the stars earned each year, counting every part with an accepted answer or one that
+    /// was solved for the real input.
+    Progress {
+        /// Also regenerate the progress section of README.md.
+        #[arg(long = "readme")]
+        readme: bool,
+    },
+    /// Show the standings and completion times of a private leaderboard.
+    Leaderboard {
+        /// The ID of the leaderboard, as in its URL.
+        #[arg(required_unless_present = "file")]
+        id: Option<String>,
+        #[arg(short = 'y', long = "year")]
+        year: Option<u32>,
+        /// Only show the completion times for this day.
+        #[arg(short = 'd', long = "day")]
+        day: Option<u32>,
+        /// Read the leaderboard from this JSON file instead of fetching it.
+        #[arg(long = "file", conflicts_with = "id")]
+        file: Option<PathBuf>,
+    },
+    /// Check the solver against the cached input of every profile and every example file, then
+    /// print whether each matches its known answer.
+    Verify {
+        #[arg(short = 'd', long = "day")]
+        day: u32,
+        #[arg(short = 'y', long = "year")]
+        year: u32,
+    },
 }
 
 fn main() {
     let args = Args::parse();
+
+    match args.command {
+        Some(Command::New { day, year }) => {
+            match scaffold::new_day(Path::new("."), year, day) {
+                Ok(path) => println!("✔ Created {path}"),
+                Err(e) => {
+                    eprintln!("✖ Failed to create the solver for day {day} of {year}: {e}");
+                    std::process::exit(1);
+                }
+            }
+            return;
+        }
+        Some(Command::Verify { .. } | Command::Progress { .. } | Command::Leaderboard { .. })
+        | None => {}
+    }
+
+    let registry = registry();
+    let profile = match Config::load().and_then(|config| config.profile(args.profile.as_deref())) {
+        Ok(profile) => profile,
+        Err(e) => {
+            eprintln!("✖ Failed to load the config: {e}");
+            std::process::exit(1);
+        }
+    };
+    let options = SolveOptions {
+        refetch: args.refetch,
+        file_extension: args.input_file_extension.clone(),
+        input_path: args.input_path.clone(),
+        part: args.part,
+        timeout: args.timeout.map(Duration::from_secs),
+        quiet: args.format.is_some(),
+        profile: profile.clone(),
+    };
+
+    if let Some(Command::Verify { day, year }) = args.command {
+        let Some(solver) = registry.get(year, day) else {
+            eprintln!("✘ No solver available for day {day} of Advent of Code {year}");
+            std::process::exit(1);
+        };
+        let variants = Config::load()
+            .and_then(|config| config.profiles())
+            .and_then(|profiles| verify::variants(&profiles, &profile, year, day));
+        let variants = match variants {
+            Ok(variants) if variants.is_empty() => {
+                eprintln!("✖ No cached inputs found for day {day} of {year}");
+                std::process::exit(1);
+            }
+            Ok(variants) => variants,
+            Err(e) => {
+                eprintln!("✖ Failed to read the cached inputs: {e}");
+                std::process::exit(1);
+            }
+        };
+        let options = SolveOptions {
+            quiet: true,
+            ..options
+        };
+        let verifications = verify::verify(solver, variants, &options);
+        verify::print_matrix(&verifications);
+        if !verifications.iter().all(|v| v.passed()) {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(Command::Leaderboard {
+        id,
+        year,
+        day,
+        file,
+    }) = &args.command
+    {
+        let year = year.unwrap_or_else(|| progress::latest_year(Utc::now().date_naive()));
+        let json = match (id, file) {
+            (_, Some(file)) => fs::read_to_string(file).map_err(anyhow::Error::from),
+            (Some(id), None) => leaderboard::fetch(&profile, year, id),
+            (None, None) => unreachable!("clap should require an ID or a file"),
+        };
+        match json.and_then(|json| Leaderboard::parse(&json)) {
+            Ok(leaderboard) => leaderboard.print(*day),
+            Err(e) => {
+                eprintln!("✖ Failed to read the leaderboard: {e}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if let Some(Command::Progress { readme }) = args.command {
+        let latest_year = progress::latest_year(Utc::now().date_naive());
+        let result = Progress::collect(&registry, &profile, latest_year).and_then(|progress| {
+            progress.print_calendars();
+            if readme {
+                let contents = fs::read_to_string("README.md")?;
+                let contents = progress::replace_section(&contents, &progress.to_markdown())?;
+                fs::write("README.md", contents)?;
+                println!("\n✔ Updated the progress in README.md");
+            }
+            Ok(())
+        });
+        if let Err(e) = result {
+            eprintln!("✖ Failed to update the progress: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if args.list {
+        for year in registry.years() {
+            let days = registry.days(year).map(|day| day.to_string());
+            println!("{year}: {}", days.collect::<Vec<_>>().join(", "));
+        }
+        return;
+    }
+
+    if let Some(threshold) = args.perf_report {
+        let histories = match History::load_all(&profile) {
+            Ok(histories) => histories,
+            Err(e) => {
+                eprintln!("✖ Failed to load the performance history: {e}");
+                std::process::exit(1);
+            }
+        };
+        let histories = histories
+            .into_iter()
+            .filter(|(year, _, _)| args.year.unwrap_or(*year) == *year)
+            .filter(|(_, day, _)| args.day.unwrap_or(*day) == *day)
+            .collect::<Vec<_>>();
+        if perf::print_report(&histories, threshold) > 0 {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if args.all || args.year_only {
+        let tick = Instant::now();
+        let solvers = registry
+            .iter()
+            .filter(|solver| args.all || Some(solver.year()) == args.year);
+        if let Some(runs) = args.bench {
+            bench(solvers, &args, &options, runs as usize);
+            return;
+        }
+        let summaries = summary::solve_all(solvers, &options);
+        match args.format {
+            Some(format) => print_records(format, &summaries),
+            None => summary::print_summary(&summaries, tick.elapsed()),
+        }
+        if !summaries.iter().all(|s| s.is_solved()) {
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let now = Utc::now();
     let day = args.day.unwrap_or(now.day());
     let month = now.month();
@@ -37,56 +311,162 @@ fn main() {
         std::process::exit(1);
     }
 
-    let _ = match year {
-        2017 => match day {
-            3 => aoc2017::day03::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            _ => {
-                eprintln!("✘ No solver available for day {day} of Advent of Code {year}");
+    if args.read {
+        match puzzle::fetch_page(&profile, year, day, args.refetch) {
+            Ok(html) => println!("{}", puzzle::render(&html)),
+            Err(e) => {
+                eprintln!("✖ Failed to fetch the puzzle description: {e}");
                 std::process::exit(1);
             }
-        },
-        2022 => match day {
-            1 => aoc2022::day01::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            2 => aoc2022::day02::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            3 => aoc2022::day03::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            4 => aoc2022::day04::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            5 => aoc2022::day05::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            6 => aoc2022::day06::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            7 => aoc2022::day07::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            8 => aoc2022::day08::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            9 => aoc2022::day09::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            10 => aoc2022::day10::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            11 => aoc2022::day11::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            12 => aoc2022::day12::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            13 => aoc2022::day13::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            17 => aoc2022::day17::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            18 => aoc2022::day18::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            _ => {
-                eprintln!("✘ No solver available for day {day} of Advent of Code {year}");
-                std::process::exit(1);
+        }
+        return;
+    }
+
+    let Some(solver) = registry.get(year, day) else {
+        eprintln!("✘ No solver available for day {day} of Advent of Code {year}");
+        std::process::exit(1);
+    };
+    if args.check_examples {
+        let passed = puzzle::fetch_page(&profile, year, day, args.refetch).and_then(|html| {
+            for path in examples::write_code_blocks(&profile, year, day, &html)? {
+                println!("Example written to {}", path.display());
             }
-        },
-        2024 => match day {
-            1 => aoc2024::day01::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            2 => aoc2024::day02::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            3 => aoc2024::day03::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            4 => aoc2024::day04::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            5 => aoc2024::day05::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            6 => aoc2024::day06::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            7 => aoc2024::day07::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            8 => aoc2024::day08::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            9 => aoc2024::day09::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            10 => aoc2024::day10::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            11 => aoc2024::day11::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            12 => aoc2024::day12::Solver.solve(args.refetch, args.input_file_extension.as_deref()),
-            _ => {
-                eprintln!("✘ No solver available for day {day} of Advent of Code {year}");
+            examples::check(solver, &html)
+        });
+        match passed {
+            Ok(true) => return,
+            Ok(false) => std::process::exit(1),
+            Err(e) => {
+                eprintln!("✖ Failed to check the examples: {e}");
                 std::process::exit(1);
             }
-        },
-        _ => {
-            eprintln!("✘ No solver available for day {day} of Advent of Code {year}");
+        }
+    }
+
+    if args.wait {
+        unlock::wait_until_unlocked(year, day);
+    }
+    if let Some(runs) = args.bench {
+        bench([solver], &args, &options, runs as usize);
+        return;
+    }
+    if args.watch {
+        if let Err(e) = watch::watch(solver, &options) {
+            eprintln!("✖ Failed to watch for changes: {e}");
+        }
+        std::process::exit(1);
+    }
+    if let Some(format) = args.format {
+        let summaries = summary::solve_all([solver], &options);
+        print_records(format, &summaries);
+        if !summaries.iter().all(|s| s.is_solved()) {
             std::process::exit(1);
         }
+        return;
+    }
+    if args.visualize {
+        visualize::start_recording();
+    }
+    let reports = solver.solve(&options);
+    if args.visualize {
+        show_frames(&args, visualize::take_frames());
+    }
+    let Ok(reports) = reports else {
+        std::process::exit(1);
     };
+
+    if let Some(part) = args.submit {
+        if let Err(e) = submit(&profile, year, day, part, &reports) {
+            eprintln!("✖ Failed to submit part {part}: {e}");
+            std::process::exit(1);
+        }
+    }
+    if !reports.iter().all(PartReport::is_solved) {
+        std::process::exit(1);
+    }
+}
+
+fn bench<'a>(
+    solvers: impl IntoIterator<Item = &'a dyn DynSolver>,
+    args: &Args,
+    options: &SolveOptions,
+    runs: usize,
+) {
+    let mut failed = false;
+    let reports = solvers
+        .into_iter()
+        .filter_map(|solver| {
+            let report = solver.bench(options, runs);
+            failed |= report.is_err();
+            report.ok()
+        })
+        .collect::<Vec<_>>();
+
+    if args.json {
+        let reports = reports.iter().map(BenchReport::to_json).collect::<Vec<_>>();
+        println!("{}", JsonValue::from(reports).pretty(2));
+    } else {
+        for report in &reports {
+            println!("{}", report.to_table());
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+fn show_frames(args: &Args, frames: Vec<visualize::Frame>) {
+    if frames.is_empty() {
+        eprintln!("✖ This solver does not record any frames to visualize");
+        return;
+    }
+    match &args.ppm {
+        Some(dir) => match visualize::write_ppm(&frames, dir) {
+            Ok(paths) => println!("✔ Wrote {} frames to {}", paths.len(), dir.display()),
+            Err(e) => eprintln!("✖ Failed to write the frames: {e}"),
+        },
+        None => visualize::play(&frames, args.fps),
+    }
+}
+
+fn print_records(format: Format, summaries: &[summary::DaySummary]) {
+    println!(
+        "{}",
+        format::render(format, &Record::from_summaries(summaries))
+    );
+}
+
+fn submit(
+    profile: &Profile,
+    year: u32,
+    day: u32,
+    part: u8,
+    reports: &[PartReport],
+) -> anyhow::Result<()> {
+    let answer = reports
+        .iter()
+        .find(|report| report.part == part)
+        .ok_or_else(|| anyhow::anyhow!("part {part} was not solved"))?
+        .answer
+        .as_ref()
+        .map_err(|e| anyhow::anyhow!("part {part} has no answer ({e})"))?;
+
+    let mut ledger = Ledger::load(profile, year, day)?;
+    if let Err(reason) = ledger.check(part, answer) {
+        return Err(anyhow::anyhow!("refusing to submit, {reason}"));
+    }
+
+    println!("Submitting {answer} as the answer to part {part}...");
+    let verdict = submit::submit_answer(&Client::for_profile(profile)?, year, day, part, answer)?;
+    ledger.record(part, answer, &verdict);
+    ledger.save(profile, year, day)?;
+
+    match verdict {
+        Verdict::Correct => println!("{}", Colour::Green.paint(format!("✔ {verdict}"))),
+        Verdict::AlreadySolved | Verdict::RateLimited(_) => {
+            println!("{}", Colour::Yellow.paint(format!("✖ {verdict}")))
+        }
+        _ => println!("{}", Colour::Red.paint(format!("✖ {verdict}"))),
+    }
+    Ok(())
 }
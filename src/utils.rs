@@ -8,7 +8,6 @@ use once_cell_regex::regex;
 
 use self::grid::Grid;
 
-pub mod aoc;
 pub mod coordinates;
 pub mod coords;
 pub mod directions;
@@ -51,7 +50,7 @@ pub fn ocr(grid: &Grid<char>) -> String {
     };
     let mut s = String::new();
     let (mut start, mut end) = (0, 5);
-    while end < grid.width() {
+    while end <= grid.width() {
         let c = grid
             .rows()
             .flat_map(|row| row.skip(start).take(5))
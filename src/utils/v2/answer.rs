@@ -0,0 +1,95 @@
+use std::fmt::Display;
+
+use crate::utils::{self, grid::Grid};
+
+/// The answer to one part of a puzzle.
+pub trait Answer {
+    /// Returns the answer as it would be submitted, or why the part has no answer.
+    fn submission(&self) -> Result<String, String>;
+}
+
+macro_rules! impl_answer_for_display {
+    ($($t:ty),* $(,)?) => {
+        $(impl Answer for $t {
+            fn submission(&self) -> Result<String, String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+impl_answer_for_display!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, String, &str
+);
+
+impl<T, E> Answer for Result<T, E>
+where
+    T: Answer,
+    E: Display,
+{
+    fn submission(&self) -> Result<String, String> {
+        match self {
+            Ok(answer) => answer.submission(),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+impl<T: Answer> Answer for Option<T> {
+    fn submission(&self) -> Result<String, String> {
+        match self {
+            Some(answer) => answer.submission(),
+            None => Err("no answer".to_owned()),
+        }
+    }
+}
+
+/// A grid of `#` pixels spelling out capital letters, which are read with [`utils::ocr`].
+impl Answer for Grid<char> {
+    fn submission(&self) -> Result<String, String> {
+        let letters = utils::ocr(self);
+        if letters.contains('?') {
+            Err(format!("could not read all of the letters in\n{self}"))
+        } else {
+            Ok(letters)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::Answer;
+    use crate::utils::grid::Grid;
+
+    #[rstest]
+    #[case(Ok(42), Ok("42"))]
+    #[case(Err("overflow"), Err("overflow"))]
+    fn test_result(#[case] answer: Result<u64, &str>, #[case] expected: Result<&str, &str>) {
+        let expected = expected.map(str::to_owned).map_err(str::to_owned);
+        assert_eq!(answer.submission(), expected);
+    }
+
+    #[rstest]
+    #[case(Some("abc"), Ok("abc"))]
+    #[case(None, Err("no answer"))]
+    fn test_option(#[case] answer: Option<&str>, #[case] expected: Result<&str, &str>) {
+        let expected = expected.map(str::to_owned).map_err(str::to_owned);
+        assert_eq!(answer.submission(), expected);
+    }
+
+    #[test]
+    fn test_grid() {
+        let rows = [
+            "#  #  ### ",
+            "#  #   #  ",
+            "####   #  ",
+            "#  #   #  ",
+            "#  #   #  ",
+            "#  #  ### ",
+        ];
+        let grid = Grid::new(rows.map(|row| row.chars().collect()).to_vec());
+        assert_eq!(grid.submission(), Ok("HI".to_owned()));
+    }
+}
@@ -0,0 +1,149 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use json::{object, JsonValue};
+use statrs::statistics::{Data, Distribution, Max, Median, Min};
+
+/// Timing statistics over repeated runs of the same step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+        let data = Data::new(
+            samples
+                .iter()
+                .map(Duration::as_secs_f64)
+                .collect::<Vec<_>>(),
+        );
+        // The sample standard deviation is undefined for a single run.
+        let std_dev = data.std_dev().filter(|s| !s.is_nan()).unwrap_or(0.0);
+        Self {
+            min: Duration::from_secs_f64(data.min()),
+            median: Duration::from_secs_f64(data.median()),
+            mean: Duration::from_secs_f64(data.mean().unwrap()),
+            max: Duration::from_secs_f64(data.max()),
+            std_dev: Duration::from_secs_f64(std_dev),
+        }
+    }
+
+    pub fn to_json(&self) -> JsonValue {
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        object! {
+            min_ms: ms(self.min),
+            median_ms: ms(self.median),
+            mean_ms: ms(self.mean),
+            max_ms: ms(self.max),
+            std_dev_ms: ms(self.std_dev),
+        }
+    }
+}
+
+/// Timing statistics for parsing the input and solving each part of one puzzle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    pub year: u32,
+    pub day: u32,
+    pub runs: usize,
+    pub parse: Stats,
+    pub parts: Vec<Stats>,
+}
+
+impl BenchReport {
+    pub fn to_json(&self) -> JsonValue {
+        let mut report = object! {
+            year: self.year,
+            day: self.day,
+            runs: self.runs,
+            parse: self.parse.to_json(),
+        };
+        for (part, stats) in (1..).zip(&self.parts) {
+            report[format!("part{part}")] = stats.to_json();
+        }
+        report
+    }
+
+    /// Formats the report as one line per step, for the terminal.
+    pub fn to_table(&self) -> String {
+        let steps = std::iter::once(("Parse".to_owned(), &self.parse)).chain(
+            (1..)
+                .zip(&self.parts)
+                .map(|(part, stats)| (format!("Part {part}"), stats)),
+        );
+        let puzzle = format!("{}/{:02}", self.year, self.day);
+        steps
+            .enumerate()
+            .map(|(i, (step, stats))| {
+                format!(
+                    "{:<7}  {:<6}  min {}  median {}  mean {} ± {}  max {}",
+                    if i == 0 { puzzle.as_str() } else { "" },
+                    step,
+                    format_ms(stats.min),
+                    format_ms(stats.median),
+                    format_ms(stats.mean),
+                    format_ms(stats.std_dev),
+                    format_ms(stats.max),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn format_ms(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+/// Runs `f` a few times to warm up caches, then `runs` more times while timing each run.
+pub fn measure<T>(runs: usize, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..warmup_runs(runs) {
+        black_box(f());
+    }
+    let samples = (0..runs)
+        .map(|_| {
+            let tick = Instant::now();
+            let result = f();
+            let elapsed = tick.elapsed();
+            black_box(result);
+            elapsed
+        })
+        .collect::<Vec<_>>();
+    Stats::from_samples(&samples)
+}
+
+fn warmup_runs(runs: usize) -> usize {
+    runs.div_ceil(10)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.max, Duration::from_millis(4));
+        assert!((stats.std_dev.as_secs_f64() - 0.001290994).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stats_single_run() {
+        let stats = Stats::from_samples(&[Duration::from_millis(5)]);
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+}
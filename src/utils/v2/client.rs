@@ -0,0 +1,380 @@
+use std::{
+    cell::RefCell, collections::VecDeque, fmt::Display, fs, path::PathBuf, rc::Rc, thread,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context, Result};
+
+use crate::utils::v2::config::Profile;
+
+pub const USER_AGENT: &str =
+    "https://github.com/jontmy/aoc-rust/blob/master/src/utils/v2/client.rs by jontmy";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The server which puzzle inputs are fetched from and answers are submitted to.
+/// Overridden with `AOC_BASE_URL`, e.g. to point at a local mock server.
+pub fn base_url() -> String {
+    dotenv::dotenv().ok();
+    dotenv::var("AOC_BASE_URL")
+        .map(|url| url.trim_end_matches('/').to_owned())
+        .unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned())
+}
+
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends requests to adventofcode.com, or something pretending to be it.
+/// Paths are relative to the server root, e.g. `/2024/day/6/input`.
+pub trait Transport {
+    fn get(&self, path: &str) -> Result<Response>;
+    fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<Response>;
+}
+
+/// Sends real HTTP requests, authenticated with a session cookie.
+pub struct ReqwestTransport {
+    client: reqwest::blocking::Client,
+    base_url: String,
+    session_token: String,
+}
+
+impl ReqwestTransport {
+    pub fn new(base_url: &str, session_token: &str) -> ReqwestTransport {
+        ReqwestTransport {
+            client: reqwest::blocking::Client::new(),
+            base_url: base_url.to_owned(),
+            session_token: session_token.to_owned(),
+        }
+    }
+
+    fn send(&self, request: reqwest::blocking::RequestBuilder) -> Result<Response> {
+        let response = request
+            .header("Cookie", format!("session={}", self.session_token))
+            .header("User-Agent", USER_AGENT)
+            .send()?;
+        Ok(Response {
+            status: response.status().as_u16(),
+            body: response.text()?,
+        })
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn get(&self, path: &str) -> Result<Response> {
+        self.send(self.client.get(format!("{}{path}", self.base_url)))
+    }
+
+    fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<Response> {
+        self.send(
+            self.client
+                .post(format!("{}{path}", self.base_url))
+                .form(form),
+        )
+    }
+}
+
+/// Serves responses from files in a directory, laid out like the paths on the server, e.g.
+/// `{root}/2024/day/6/input`. Paths which are directories are served from their `index.html`.
+/// Missing files are served as 404s.
+pub struct FixtureTransport {
+    root: PathBuf,
+}
+
+impl FixtureTransport {
+    pub fn new(root: impl Into<PathBuf>) -> FixtureTransport {
+        FixtureTransport { root: root.into() }
+    }
+}
+
+impl Transport for FixtureTransport {
+    fn get(&self, path: &str) -> Result<Response> {
+        let mut path = self.root.join(path.trim_start_matches('/'));
+        if path.is_dir() {
+            path.push("index.html");
+        }
+        match fs::read_to_string(path) {
+            Ok(body) => Ok(Response { status: 200, body }),
+            Err(_) => Ok(Response {
+                status: 404,
+                body: "404 Not Found".to_owned(),
+            }),
+        }
+    }
+
+    fn post_form(&self, path: &str, _form: &[(&str, &str)]) -> Result<Response> {
+        self.get(path)
+    }
+}
+
+/// Replays queued responses in order, and remembers every request it was sent.
+#[derive(Default)]
+pub struct MockTransport {
+    responses: RefCell<VecDeque<Result<Response>>>,
+    requests: Rc<RefCell<Vec<String>>>,
+}
+
+impl MockTransport {
+    /// A handle to the requests sent so far, which stays usable after the transport is moved.
+    pub fn requests(&self) -> Rc<RefCell<Vec<String>>> {
+        Rc::clone(&self.requests)
+    }
+
+    pub fn respond(self, status: u16, body: &str) -> MockTransport {
+        self.responses.borrow_mut().push_back(Ok(Response {
+            status,
+            body: body.to_owned(),
+        }));
+        self
+    }
+
+    pub fn fail(self, message: &str) -> MockTransport {
+        self.responses
+            .borrow_mut()
+            .push_back(Err(anyhow!(message.to_owned())));
+        self
+    }
+
+    fn next(&self, request: String) -> Result<Response> {
+        self.requests.borrow_mut().push(request);
+        self.responses
+            .borrow_mut()
+            .pop_front()
+            .unwrap_or_else(|| Err(anyhow!("no more mock responses")))
+    }
+}
+
+impl Transport for MockTransport {
+    fn get(&self, path: &str) -> Result<Response> {
+        self.next(format!("GET {path}"))
+    }
+
+    fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<Response> {
+        let form = form.iter().map(|(k, v)| format!("{k}={v}"));
+        self.next(format!(
+            "POST {path} {}",
+            form.collect::<Vec<_>>().join("&")
+        ))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ClientError {
+    /// The session cookie is missing, malformed or has expired.
+    SessionExpired,
+    /// The puzzle has not been released yet.
+    NotUnlocked,
+    Status(u16, String),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::SessionExpired => {
+                write!(
+                    f,
+                    "the session token has expired, please update SESSION_TOKEN"
+                )
+            }
+            ClientError::NotUnlocked => write!(
+                f,
+                "the puzzle has not unlocked yet, run with --wait to wait for it"
+            ),
+            ClientError::Status(status, body) => {
+                write!(f, "server responded with {status}: {body}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// Checks the status of responses from a [`Transport`], retrying server and network errors on
+/// requests which are safe to repeat.
+pub struct Client {
+    transport: Box<dyn Transport>,
+    retries: u32,
+    backoff: Duration,
+}
+
+impl Client {
+    pub fn new(transport: impl Transport + 'static) -> Client {
+        Client {
+            transport: Box::new(transport),
+            retries: 3,
+            backoff: Duration::from_millis(500),
+        }
+    }
+
+    /// Serves fixtures from `AOC_FIXTURES` if it is set, otherwise talks to [`base_url`] with the
+    /// session token of the profile.
+    pub fn for_profile(profile: &Profile) -> Result<Client> {
+        dotenv::dotenv().ok();
+        match dotenv::var("AOC_FIXTURES") {
+            Ok(root) => Ok(Client::new(FixtureTransport::new(root))),
+            Err(_) => Ok(Client::new(ReqwestTransport::new(
+                &base_url(),
+                &profile.session_token()?,
+            ))),
+        }
+    }
+
+    /// Sets how many times a GET request is retried, and the delay before the first retry.
+    /// The delay doubles after every retry.
+    pub fn with_retries(mut self, retries: u32, backoff: Duration) -> Client {
+        self.retries = retries;
+        self.backoff = backoff;
+        self
+    }
+
+    pub fn get(&self, path: &str) -> Result<String> {
+        self.with_retry(|| self.transport.get(path))
+    }
+
+    /// Sends a form exactly once. Posts are never retried, since a request which failed on the
+    /// way back may still have gone through, e.g. submitting the same answer twice.
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        self.transport
+            .post_form(path, form)
+            .and_then(Client::check_status)
+    }
+
+    fn with_retry(&self, send: impl Fn() -> Result<Response>) -> Result<String> {
+        let mut backoff = self.backoff;
+        let mut attempt = 0;
+        loop {
+            let result = send().and_then(Client::check_status);
+            let retryable = match &result {
+                Ok(_) => false,
+                Err(e) => match e.downcast_ref::<ClientError>() {
+                    Some(ClientError::Status(status, _)) => *status >= 500,
+                    Some(_) => false,
+                    None => true,
+                },
+            };
+            if !retryable || attempt >= self.retries {
+                return result;
+            }
+            thread::sleep(backoff);
+            backoff *= 2;
+            attempt += 1;
+        }
+    }
+
+    fn check_status(response: Response) -> Result<String> {
+        let body = response.body;
+        match response.status {
+            200 => Ok(body),
+            404 if body.starts_with("Please don't repeatedly request this endpoint") => {
+                Err(ClientError::NotUnlocked.into())
+            }
+            400 | 401 | 403 if body.contains("log in") || body.contains("Log In") => {
+                Err(ClientError::SessionExpired.into())
+            }
+            status => {
+                let summary = body.lines().next().unwrap_or_default().trim().to_owned();
+                Err(ClientError::Status(status, summary).into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+        time::Duration,
+    };
+
+    use rstest::rstest;
+
+    use super::{Client, ClientError, MockTransport, ReqwestTransport, Transport};
+
+    fn client(transport: MockTransport) -> Client {
+        Client::new(transport).with_retries(2, Duration::ZERO)
+    }
+
+    #[rstest]
+    #[case(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        ClientError::SessionExpired
+    )]
+    #[case(404, "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.", ClientError::NotUnlocked)]
+    #[case(404, "404 Not Found", ClientError::Status(404, "404 Not Found".to_owned()))]
+    fn test_status_errors(#[case] status: u16, #[case] body: &str, #[case] expected: ClientError) {
+        let error = client(MockTransport::default().respond(status, body))
+            .get("/2024/day/6/input")
+            .unwrap_err();
+        assert_eq!(error.downcast_ref::<ClientError>(), Some(&expected));
+    }
+
+    #[test]
+    fn test_retries_server_errors() {
+        let transport = MockTransport::default()
+            .respond(500, "Internal Server Error")
+            .fail("connection reset")
+            .respond(200, "1 2 3");
+        assert_eq!(client(transport).get("/2024/day/6/input").unwrap(), "1 2 3");
+    }
+
+    #[test]
+    fn test_gives_up_after_retries() {
+        let transport = MockTransport::default()
+            .respond(502, "Bad Gateway")
+            .respond(502, "Bad Gateway")
+            .respond(502, "Bad Gateway")
+            .respond(200, "1 2 3");
+        assert!(client(transport).get("/2024/day/6/input").is_err());
+    }
+
+    #[rstest]
+    #[case(MockTransport::default().respond(500, "Internal Server Error"))]
+    #[case(MockTransport::default().fail("connection reset"))]
+    fn test_never_retries_posts(#[case] transport: MockTransport) {
+        let requests = transport.requests();
+        let transport = transport.respond(200, "That's the right answer!");
+        assert!(client(transport)
+            .post_form("/2024/day/6/answer", &[("level", "1"), ("answer", "41")])
+            .is_err());
+        assert_eq!(requests.borrow().len(), 1);
+    }
+
+    #[test]
+    fn test_reqwest_transport_against_local_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut head = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\n1 2 3"
+            )
+            .unwrap();
+            head
+        });
+
+        let response = ReqwestTransport::new(&base_url, "token")
+            .get("/2024/day/6/input")
+            .unwrap();
+        let head = server.join().unwrap();
+
+        assert_eq!((response.status, response.body.as_str()), (200, "1 2 3"));
+        assert!(head.starts_with("GET /2024/day/6/input HTTP/1.1"));
+        assert!(head.contains("cookie: session=token"));
+    }
+}
@@ -0,0 +1,191 @@
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+use anyhow::{anyhow, bail, Result};
+use serde::Deserialize;
+
+const DEFAULT_PATH: &str = "aoc.toml";
+
+/// Settings read from `aoc.toml`, or from the file named by `AOC_CONFIG`. For example:
+///
+/// ```toml
+/// cache_dir = "../aoc-data"
+///
+/// [profiles.alice]
+/// session_token = "53616c74..."
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Where inputs, answers and timings are kept, the current directory by default. Overridden
+    /// with `AOC_CACHE_DIR`.
+    cache_dir: Option<PathBuf>,
+    #[serde(default)]
+    profiles: BTreeMap<String, ProfileConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProfileConfig {
+    /// Falls back to `SESSION_TOKEN_{NAME}`, e.g. `SESSION_TOKEN_ALICE`.
+    session_token: Option<String>,
+    /// `profiles/{name}` in the cache directory by default.
+    cache_dir: Option<PathBuf>,
+}
+
+impl Config {
+    /// Loads the config file, or the default config if there is none.
+    pub fn load() -> Result<Config> {
+        dotenv::dotenv().ok();
+        let path = match dotenv::var("AOC_CONFIG") {
+            Ok(path) => PathBuf::from(path),
+            Err(_) if !PathBuf::from(DEFAULT_PATH).exists() => return Ok(Config::default()),
+            Err(_) => PathBuf::from(DEFAULT_PATH),
+        };
+        let config = fs::read_to_string(&path)
+            .map_err(|e| anyhow!("failed to read config {}: {e}", path.display()))?;
+        toml::from_str(&config).map_err(|e| anyhow!("malformed config {}: {e}", path.display()))
+    }
+
+    /// The names of every profile in the config file.
+    pub fn profile_names(&self) -> impl Iterator<Item = &str> {
+        self.profiles.keys().map(String::as_str)
+    }
+
+    /// Resolves the named profile, or the one named by `AOC_PROFILE`, or the default profile.
+    pub fn profile(&self, name: Option<&str>) -> Result<Profile> {
+        dotenv::dotenv().ok();
+        self.resolve(name, |key| dotenv::var(key).ok())
+    }
+
+    /// Resolves the default profile followed by every profile in the config file.
+    pub fn profiles(&self) -> Result<Vec<Profile>> {
+        dotenv::dotenv().ok();
+        let env = |key: &str| match key {
+            "AOC_PROFILE" => None,
+            _ => dotenv::var(key).ok(),
+        };
+        std::iter::once(None)
+            .chain(self.profile_names().map(Some))
+            .map(|name| self.resolve(name, env))
+            .collect()
+    }
+
+    fn resolve(&self, name: Option<&str>, env: impl Fn(&str) -> Option<String>) -> Result<Profile> {
+        let root = env("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .or_else(|| self.cache_dir.clone())
+            .unwrap_or_else(|| PathBuf::from("."));
+        let Some(name) = name.map(str::to_owned).or_else(|| env("AOC_PROFILE")) else {
+            return Ok(Profile {
+                name: None,
+                dir: root,
+                session_token: env("SESSION_TOKEN"),
+            });
+        };
+
+        let token_var = format!("SESSION_TOKEN_{}", name.to_uppercase().replace('-', "_"));
+        let profile = match self.profiles.get(&name) {
+            Some(profile) => profile,
+            None if env(&token_var).is_some() => &ProfileConfig::default(),
+            None => bail!("unknown profile {name}, add it to {DEFAULT_PATH} or set {token_var}"),
+        };
+        Ok(Profile {
+            dir: match &profile.cache_dir {
+                Some(dir) => dir.clone(),
+                None => root.join("profiles").join(&name),
+            },
+            session_token: profile.session_token.clone().or_else(|| env(&token_var)),
+            name: Some(name),
+        })
+    }
+}
+
+/// One Advent of Code account: the session token to fetch its inputs with, and the directory in
+/// which its inputs, answers and timings are kept apart from those of other accounts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    /// `None` for the default profile.
+    pub name: Option<String>,
+    pub dir: PathBuf,
+    session_token: Option<String>,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Profile {
+            name: None,
+            dir: PathBuf::from("."),
+            session_token: None,
+        }
+    }
+}
+
+impl Profile {
+    pub fn session_token(&self) -> Result<String> {
+        match (&self.session_token, &self.name) {
+            (Some(token), _) => Ok(token.clone()),
+            (None, None) => Err(anyhow!("environment variable SESSION_TOKEN should be set")),
+            (None, Some(name)) => Err(anyhow!("profile {name} has no session token")),
+        }
+    }
+
+    /// The profile's name as it is shown to the user.
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("default")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use rstest::rstest;
+
+    use super::Config;
+
+    const CONFIG: &str = r#"
+        cache_dir = "data"
+
+        [profiles.alice]
+        session_token = "alice-token"
+
+        [profiles.bob]
+        cache_dir = "/tmp/bob"
+    "#;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        |key| {
+            vars.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.to_string())
+        }
+    }
+
+    #[rstest]
+    #[case(None, &[], None, "data", None)]
+    #[case(None, &[("SESSION_TOKEN", "t")], None, "data", Some("t"))]
+    #[case(None, &[("AOC_CACHE_DIR", "cache")], None, "cache", None)]
+    #[case(Some("alice"), &[], Some("alice"), "data/profiles/alice", Some("alice-token"))]
+    #[case(None, &[("AOC_PROFILE", "alice")], Some("alice"), "data/profiles/alice", Some("alice-token"))]
+    #[case(Some("bob"), &[("SESSION_TOKEN_BOB", "b")], Some("bob"), "/tmp/bob", Some("b"))]
+    #[case(Some("carol"), &[("SESSION_TOKEN_CAROL", "c")], Some("carol"), "data/profiles/carol", Some("c"))]
+    fn test_resolve(
+        #[case] name: Option<&str>,
+        #[case] vars: &[(&str, &str)],
+        #[case] expected_name: Option<&str>,
+        #[case] expected_dir: &str,
+        #[case] expected_token: Option<&str>,
+    ) {
+        let config: Config = toml::from_str(CONFIG).unwrap();
+        let profile = config.resolve(name, env(vars)).unwrap();
+        assert_eq!(profile.name.as_deref(), expected_name);
+        assert_eq!(profile.dir, PathBuf::from(expected_dir));
+        assert_eq!(profile.session_token.as_deref(), expected_token);
+    }
+
+    #[test]
+    fn test_unknown_profile() {
+        let config: Config = toml::from_str(CONFIG).unwrap();
+        assert!(config.resolve(Some("carol"), env(&[])).is_err());
+    }
+}
@@ -0,0 +1,156 @@
+use std::{fs, path::PathBuf};
+
+use ansi_term::Colour;
+use anyhow::Result;
+use once_cell_regex::regex;
+
+use crate::utils::v2::{
+    config::Profile,
+    puzzle::{articles, unescape},
+    registry::DynSolver,
+    solver::input_path,
+};
+
+/// An example input from the puzzle description, and the answer it should produce.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub part: u8,
+    pub input: String,
+    pub expected: Option<String>,
+}
+
+/// Extracts every `<pre><code>` block from a puzzle page, in order.
+pub fn code_blocks(html: &str) -> Vec<String> {
+    regex!(r"(?s)<pre><code>(.*?)</code></pre>")
+        .captures_iter(html)
+        .map(|captures| {
+            let block = regex!(r"<[^>]*>").replace_all(&captures[1], "");
+            unescape(&block).trim().to_owned()
+        })
+        .collect()
+}
+
+/// Guesses the example for each part: the first code block in the description of that part (or
+/// of part 1, if part 2 reuses it), and the last `<code><em>` in the description as its answer.
+pub fn examples(html: &str) -> Vec<Example> {
+    let articles = articles(html);
+    let first_block = articles
+        .first()
+        .and_then(|a| code_blocks(a).into_iter().next());
+
+    articles
+        .iter()
+        .zip(1..)
+        .filter_map(|(article, part)| {
+            let input = code_blocks(article)
+                .into_iter()
+                .next()
+                .or_else(|| first_block.clone())?;
+            let expected = regex!(r"<code><em>(.*?)</em></code>")
+                .captures_iter(article)
+                .last()
+                .map(|captures| unescape(&captures[1]));
+            Some(Example {
+                part,
+                input,
+                expected,
+            })
+        })
+        .collect()
+}
+
+/// Writes each code block to `input/{year}/{day}.ex1`, `.ex2`, ..., keeping any existing files.
+pub fn write_code_blocks(
+    profile: &Profile,
+    year: u32,
+    day: u32,
+    html: &str,
+) -> Result<Vec<PathBuf>> {
+    let mut written = Vec::new();
+    for (i, block) in code_blocks(html).into_iter().enumerate() {
+        let path = input_path(profile, year, day, Some(&format!("ex{}", i + 1)));
+        if path.exists() {
+            continue;
+        }
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(&path, block)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Runs the solver against each example with a known answer, returning whether all of them passed.
+pub fn check(solver: &dyn DynSolver, html: &str) -> Result<bool> {
+    let mut passed = true;
+    for example in examples(html) {
+        let Some(expected) = example.expected else {
+            println!(
+                "? No expected answer found for the part {} example",
+                example.part
+            );
+            continue;
+        };
+        let report = solver.solve_part(example.part, &example.input)?;
+        match report.answer {
+            Ok(answer) if answer == expected => println!(
+                "{}",
+                Colour::Green.paint(format!("✔ Part {} example passed", example.part))
+            ),
+            answer => {
+                passed = false;
+                let answer = answer.unwrap_or_else(|e| format!("failed with {e}"));
+                println!(
+                    "{}",
+                    Colour::Red.paint(format!(
+                        "✖ Part {} example failed (answer: {}, expected: {})",
+                        example.part, answer, expected
+                    ))
+                );
+            }
+        }
+    }
+    Ok(passed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{code_blocks, examples, Example};
+
+    const HTML: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>Another illustration:</p>
+<pre><code>a &lt; b</code></pre>
+<p>In the example above, this is <code>2 + 1 = <em>3</em></code>; the total is <code><em>11</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>So, for these example lists, the similarity score at the end of this process is <code><em>31</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_code_blocks() {
+        assert_eq!(code_blocks(HTML), vec!["3   4\n4   3", "a < b"]);
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(
+            examples(HTML),
+            vec![
+                Example {
+                    part: 1,
+                    input: "3   4\n4   3".to_owned(),
+                    expected: Some("11".to_owned()),
+                },
+                Example {
+                    part: 2,
+                    input: "3   4\n4   3".to_owned(),
+                    expected: Some("31".to_owned()),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use std::time::Duration;
+
+use chrono::{SecondsFormat, Utc};
+use clap::ValueEnum;
+use json::{object, JsonValue};
+use toml::value::Datetime;
+
+use crate::utils::v2::{
+    solver::{InputSource, Status},
+    summary::DaySummary,
+};
+
+/// A machine-readable format for the results of a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Json,
+    Csv,
+    Tsv,
+}
+
+const COLUMNS: [&str; 7] = [
+    "year",
+    "day",
+    "part",
+    "answer",
+    "elapsed_ms",
+    "source",
+    "status",
+];
+
+/// The result of one part of a puzzle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub elapsed_ms: f64,
+    /// Where the input came from, unless it could not be fetched at all.
+    pub source: Option<InputSource>,
+    pub status: Status,
+}
+
+impl Record {
+    /// Flattens the summaries into one record per part. Both parts of a puzzle which could not be
+    /// fetched or parsed are recorded as failed.
+    pub fn from_summaries(summaries: &[DaySummary]) -> Vec<Record> {
+        summaries
+            .iter()
+            .flat_map(|summary| match &summary.outcome {
+                Ok(reports) => reports
+                    .iter()
+                    .map(|report| Record {
+                        year: summary.year,
+                        day: summary.day,
+                        part: report.part,
+                        answer: report.answer.as_ref().ok().cloned(),
+                        elapsed_ms: report.elapsed.as_secs_f64() * 1000.0,
+                        source: Some(report.source),
+                        status: report.status,
+                    })
+                    .collect::<Vec<_>>(),
+                Err(_) => [1, 2]
+                    .map(|part| Record {
+                        year: summary.year,
+                        day: summary.day,
+                        part,
+                        answer: None,
+                        elapsed_ms: 0.0,
+                        source: None,
+                        status: Status::Failed,
+                    })
+                    .to_vec(),
+            })
+            .collect()
+    }
+
+    fn fields(&self) -> [String; 7] {
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            self.answer.clone().unwrap_or_default(),
+            format!("{:.3}", self.elapsed_ms),
+            self.source.map_or("", |s| s.as_str()).to_owned(),
+            self.status.as_str().to_owned(),
+        ]
+    }
+
+    fn to_json(&self) -> JsonValue {
+        object! {
+            year: self.year,
+            day: self.day,
+            part: self.part,
+            answer: self.answer.clone(),
+            elapsed_ms: self.elapsed_ms,
+            source: self.source.map(|s| s.as_str()),
+            status: self.status.as_str(),
+        }
+    }
+}
+
+/// Renders the records as a JSON array, or as a table with a header row. Missing answers and
+/// sources are `null` in JSON and empty fields otherwise.
+pub fn render(format: Format, records: &[Record]) -> String {
+    match format {
+        Format::Json => {
+            let records = records.iter().map(Record::to_json).collect::<Vec<_>>();
+            JsonValue::from(records).pretty(2)
+        }
+        Format::Csv => table(records, ",", csv_field),
+        Format::Tsv => table(records, "\t", tsv_field),
+    }
+}
+
+fn table(records: &[Record], separator: &str, escape: fn(&str) -> String) -> String {
+    let header = COLUMNS.join(separator);
+    let rows = records.iter().map(|record| {
+        record
+            .fields()
+            .iter()
+            .map(|field| escape(field))
+            .collect::<Vec<_>>()
+            .join(separator)
+    });
+    std::iter::once(header)
+        .chain(rows)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Quotes fields containing commas, quotes or line breaks, as in RFC 4180.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// Escapes tabs and line breaks with backslashes, so that each record stays on one line.
+fn tsv_field(field: &str) -> String {
+    field
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+/// Formats a duration in milliseconds, or in seconds once it is at least one second long.
+pub(crate) fn format_duration(duration: Duration) -> String {
+    let ms = duration.as_secs_f64() * 1000.0;
+    if ms < 1000.0 {
+        format!("{ms:.1}ms")
+    } else {
+        format!("{:.2}s", ms / 1000.0)
+    }
+}
+
+/// The current time to the second, for the timestamps kept in TOML files.
+pub(crate) fn toml_now() -> Datetime {
+    Utc::now()
+        .to_rfc3339_opts(SecondsFormat::Secs, true)
+        .parse()
+        .expect("RFC 3339 timestamps should be valid TOML datetimes")
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{csv_field, render, tsv_field, Format, Record};
+    use crate::utils::v2::solver::{InputSource, Status};
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                year: 2024,
+                day: 9,
+                part: 1,
+                answer: Some("1928".to_owned()),
+                elapsed_ms: 1.5,
+                source: Some(InputSource::File),
+                status: Status::Solved,
+            },
+            Record {
+                year: 2024,
+                day: 9,
+                part: 2,
+                answer: None,
+                elapsed_ms: 0.0,
+                source: None,
+                status: Status::Failed,
+            },
+        ]
+    }
+
+    #[rstest]
+    #[case("1928", "1928")]
+    #[case("a,b", "\"a,b\"")]
+    #[case("say \"hi\"", "\"say \"\"hi\"\"\"")]
+    #[case("#\n#", "\"#\n#\"")]
+    fn test_csv_field(#[case] field: &str, #[case] expected: &str) {
+        assert_eq!(csv_field(field), expected);
+    }
+
+    #[rstest]
+    #[case("1928", "1928")]
+    #[case("a\tb", "a\\tb")]
+    #[case("#\n#", "#\\n#")]
+    #[case("a\\b", "a\\\\b")]
+    fn test_tsv_field(#[case] field: &str, #[case] expected: &str) {
+        assert_eq!(tsv_field(field), expected);
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let expected = "year,day,part,answer,elapsed_ms,source,status\n\
+                        2024,9,1,1928,1.500,file,solved\n\
+                        2024,9,2,,0.000,,failed";
+        assert_eq!(render(Format::Csv, &records()), expected);
+    }
+
+    #[test]
+    fn test_render_json() {
+        let rendered = json::parse(&render(Format::Json, &records())).unwrap();
+        assert_eq!(rendered.len(), 2);
+        assert_eq!(rendered[0]["answer"], "1928");
+        assert_eq!(rendered[0]["source"], "file");
+        assert!(rendered[1]["answer"].is_null());
+        assert_eq!(rendered[1]["status"], "failed");
+    }
+}
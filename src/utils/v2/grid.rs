@@ -194,7 +194,7 @@ where
         F: Fn((I, I), &T) -> Vec<(I, I)>;
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DenseGrid<T, I = DefaultIndexType> {
     grid: Array2<T>,
     index_type: PhantomData<I>,
@@ -0,0 +1,252 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+
+use ansi_term::Style;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use json::JsonValue;
+
+use crate::utils::v2::{client::Client, config::Profile, summary::pad_table, unlock::unlock_time};
+
+/// adventofcode.com asks that private leaderboards are fetched at most once every 15 minutes.
+const CACHE_LIFETIME: Duration = Duration::from_secs(15 * 60);
+
+pub struct Member {
+    pub name: String,
+    pub local_score: u64,
+    pub stars: u64,
+    /// When each part of each day was solved, keyed by day.
+    pub completions: BTreeMap<u32, [Option<DateTime<Utc>>; 2]>,
+}
+
+impl Member {
+    fn parse(id: &str, json: &JsonValue) -> Result<Member> {
+        let name = json["name"]
+            .as_str()
+            .map_or_else(|| format!("(anonymous user #{id})"), str::to_owned);
+        let mut completions = BTreeMap::new();
+        for (day, parts) in json["completion_day_level"].entries() {
+            let day = day
+                .parse()
+                .with_context(|| format!("malformed day {day} for member {id}"))?;
+            let timestamp = |part: &str| {
+                parts[part]["get_star_ts"]
+                    .as_i64()
+                    .and_then(|ts| DateTime::from_timestamp(ts, 0))
+            };
+            completions.insert(day, [timestamp("1"), timestamp("2")]);
+        }
+        Ok(Member {
+            name,
+            local_score: json["local_score"].as_u64().unwrap_or(0),
+            stars: json["stars"].as_u64().unwrap_or(0),
+            completions,
+        })
+    }
+
+    fn last_star(&self) -> Option<DateTime<Utc>> {
+        self.completions.values().flatten().flatten().max().copied()
+    }
+}
+
+/// A private leaderboard, with its members ordered by local score.
+pub struct Leaderboard {
+    pub year: u32,
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    /// Parses the JSON served at `/{year}/leaderboard/private/view/{id}.json`.
+    pub fn parse(json: &str) -> Result<Leaderboard> {
+        let json = json::parse(json).context("malformed leaderboard")?;
+        let year = json["event"]
+            .as_str()
+            .and_then(|event| event.parse().ok())
+            .ok_or_else(|| anyhow!("leaderboard has no event year"))?;
+        let mut members = json["members"]
+            .entries()
+            .map(|(id, member)| Member::parse(id, member))
+            .collect::<Result<Vec<_>>>()?;
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(a.last_star().cmp(&b.last_star()))
+        });
+        Ok(Leaderboard { year, members })
+    }
+
+    /// Prints the standings, then the completion time of each member for each day, measured from
+    /// when the puzzle unlocked, and how long part 2 took after part 1.
+    pub fn print(&self, day: Option<u32>) {
+        let header = ["#", "Name", "Score", "Stars"].map(String::from);
+        let rows = self
+            .members
+            .iter()
+            .zip(1..)
+            .map(|(member, rank)| {
+                vec![
+                    rank.to_string(),
+                    member.name.clone(),
+                    member.local_score.to_string(),
+                    member.stars.to_string(),
+                ]
+            })
+            .collect::<Vec<_>>();
+        let (header, rows) = pad_table(&header, &rows);
+        println!("\n{}", Style::new().bold().paint(header));
+        for row in rows {
+            println!("{row}");
+        }
+
+        for day in (1..=25).filter(|d| day.unwrap_or(*d) == *d) {
+            let unlocked = unlock_time(self.year, day);
+            let mut completions = self
+                .members
+                .iter()
+                .filter_map(|member| Some((member, member.completions.get(&day)?)))
+                .collect::<Vec<_>>();
+            if completions.is_empty() {
+                continue;
+            }
+            // Whoever finished part 2 first comes first, then whoever finished part 1 first.
+            completions.sort_by_key(|(_, [one, two])| (two.is_none(), *two, *one));
+
+            let header = ["Name", "Part 1", "Part 2", "Delta"].map(String::from);
+            let rows = completions
+                .iter()
+                .map(|(member, [one, two])| {
+                    let since_unlock = |t: &Option<DateTime<Utc>>| {
+                        t.map_or("-".to_owned(), |t| format_duration(t - unlocked))
+                    };
+                    let delta = match (one, two) {
+                        (Some(one), Some(two)) => format!("+{}", format_duration(*two - *one)),
+                        _ => "-".to_owned(),
+                    };
+                    vec![
+                        member.name.clone(),
+                        since_unlock(one),
+                        since_unlock(two),
+                        delta,
+                    ]
+                })
+                .collect::<Vec<_>>();
+            let (header, rows) = pad_table(&header, &rows);
+            println!("\n{}", Style::new().bold().paint(format!("Day {day}")));
+            println!("{}", Style::new().dimmed().paint(header));
+            for row in rows {
+                println!("{row}");
+            }
+        }
+    }
+}
+
+fn cache_path(profile: &Profile, year: u32, id: &str) -> PathBuf {
+    profile
+        .dir
+        .join(format!("input/{year}/leaderboard-{id}.json"))
+}
+
+/// Reads a private leaderboard from the cache if it was fetched less than 15 minutes ago, and
+/// downloads it otherwise.
+pub fn fetch(profile: &Profile, year: u32, id: &str) -> Result<String> {
+    let path = cache_path(profile, year, id);
+    let fresh = fs::metadata(&path)
+        .and_then(|metadata| metadata.modified())
+        .is_ok_and(|modified| {
+            SystemTime::now()
+                .duration_since(modified)
+                .is_ok_and(|age| age < CACHE_LIFETIME)
+        });
+    if fresh {
+        return Ok(fs::read_to_string(&path)?);
+    }
+
+    let json = Client::for_profile(profile)?
+        .get(&format!("/{year}/leaderboard/private/view/{id}.json"))?;
+    fs::create_dir_all(path.parent().unwrap())?;
+    fs::write(&path, &json)?;
+    Ok(json)
+}
+
+/// Formats a duration as `hh:mm:ss`, with the days in front if there are any.
+fn format_duration(duration: chrono::Duration) -> String {
+    let seconds = duration.num_seconds().max(0);
+    let (days, hours) = (seconds / 86400, seconds / 3600 % 24);
+    let (minutes, seconds) = (seconds / 60 % 60, seconds % 60);
+    match days {
+        0 => format!("{hours:02}:{minutes:02}:{seconds:02}"),
+        _ => format!("{days}d {hours:02}:{minutes:02}:{seconds:02}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{format_duration, Leaderboard};
+
+    const JSON: &str = r#"{
+        "owner_id": 1,
+        "event": "2024",
+        "members": {
+            "1": {
+                "id": 1, "name": "Alice", "stars": 3, "local_score": 10,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1733029800, "star_index": 1},
+                        "2": {"get_star_ts": 1733030100, "star_index": 2}
+                    },
+                    "2": {"1": {"get_star_ts": 1733119200, "star_index": 3}}
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 2, "local_score": 12,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1733029500, "star_index": 0},
+                        "2": {"get_star_ts": 1733029700, "star_index": 4}
+                    }
+                }
+            },
+            "3": {"id": 3, "name": "Carol", "stars": 0, "local_score": 0, "completion_day_level": {}}
+        }
+    }"#;
+
+    #[test]
+    fn test_parse() {
+        let leaderboard = Leaderboard::parse(JSON).unwrap();
+        assert_eq!(leaderboard.year, 2024);
+        let names = leaderboard.members.iter().map(|m| m.name.as_str());
+        assert_eq!(
+            names.collect::<Vec<_>>(),
+            ["(anonymous user #2)", "Alice", "Carol"]
+        );
+
+        let alice = &leaderboard.members[1];
+        assert_eq!(alice.stars, 3);
+        let [one, two] = alice.completions[&1];
+        assert_eq!((two.unwrap() - one.unwrap()).num_seconds(), 300);
+        assert_eq!(alice.completions[&2][1], None);
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        assert!(Leaderboard::parse("{}").is_err());
+        assert!(Leaderboard::parse("<html>").is_err());
+    }
+
+    #[rstest]
+    #[case(0, "00:00:00")]
+    #[case(3723, "01:02:03")]
+    #[case(90061, "1d 01:01:01")]
+    fn test_format_duration(#[case] seconds: i64, #[case] expected: &str) {
+        assert_eq!(
+            format_duration(chrono::Duration::seconds(seconds)),
+            expected
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use toml::value::Datetime;
+
+use crate::utils::v2::{config::Profile, format::toml_now, submit::Verdict};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    pub submitted_at: Datetime,
+}
+
+/// Every answer submitted for a puzzle along with its verdict, stored in `answers/{year}/{day}.toml`
+/// in the directory of the profile.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    fn path(profile: &Profile, year: u32, day: u32) -> PathBuf {
+        profile.dir.join(format!("answers/{year}/{day:02}.toml"))
+    }
+
+    /// Loads the ledger for a puzzle, or an empty ledger if nothing has been submitted yet.
+    pub fn load(profile: &Profile, year: u32, day: u32) -> Result<Ledger> {
+        let path = Ledger::path(profile, year, day);
+        if !path.exists() {
+            return Ok(Ledger::default());
+        }
+        let ledger = toml::from_str(&fs::read_to_string(&path)?)
+            .map_err(|e| anyhow!("malformed answer ledger {}: {e}", path.display()))?;
+        Ok(ledger)
+    }
+
+    pub fn save(&self, profile: &Profile, year: u32, day: u32) -> Result<()> {
+        let path = Ledger::path(profile, year, day);
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Records a submission, unless its verdict says nothing about the answer itself.
+    pub fn record(&mut self, part: u8, answer: &str, verdict: &Verdict) {
+        if matches!(verdict, Verdict::RateLimited(_) | Verdict::AlreadySolved) {
+            return;
+        }
+        let submitted_at = toml_now();
+        self.submissions.push(Submission {
+            part,
+            answer: answer.to_owned(),
+            verdict: verdict.clone(),
+            submitted_at,
+        });
+    }
+
+    pub fn submissions(&self, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions.iter().filter(move |s| s.part == part)
+    }
+
+    pub fn correct_answer(&self, part: u8) -> Option<&str> {
+        self.submissions(part)
+            .find(|s| s.verdict == Verdict::Correct)
+            .map(|s| s.answer.as_str())
+    }
+
+    /// Checks an answer against earlier submissions, returning why it should not be submitted if
+    /// it is already known to be wrong or lies outside the too-high/too-low bracket.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), String> {
+        if let Some(correct) = self.correct_answer(part) {
+            return Err(format!("part {part} was already solved with {correct}"));
+        }
+        if let Some(s) = self.submissions(part).find(|s| s.answer == answer) {
+            return Err(format!(
+                "{answer} was already submitted and was {}",
+                s.verdict
+            ));
+        }
+
+        let Ok(answer) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        let bound = |verdict: Verdict| {
+            self.submissions(part)
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+        if let Some(low) = bound(Verdict::TooLow).max().filter(|&low| answer <= low) {
+            return Err(format!(
+                "{answer} is not higher than {low}, which was too low"
+            ));
+        }
+        if let Some(high) = bound(Verdict::TooHigh).min().filter(|&high| answer >= high) {
+            return Err(format!(
+                "{answer} is not lower than {high}, which was too high"
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::Ledger;
+    use crate::utils::v2::{config::Profile, format::toml_now, submit::Verdict};
+
+    fn ledger() -> Ledger {
+        let mut ledger = Ledger::default();
+        ledger.record(1, "100", &Verdict::TooLow);
+        ledger.record(1, "500", &Verdict::TooHigh);
+        ledger.record(1, "300", &Verdict::Wrong);
+        ledger.record(2, "42", &Verdict::Correct);
+        ledger
+    }
+
+    #[rstest]
+    #[case(1, "250", true)]
+    #[case(1, "100", false)]
+    #[case(1, "50", false)]
+    #[case(1, "500", false)]
+    #[case(1, "501", false)]
+    #[case(1, "300", false)]
+    #[case(1, "ABCDEF", true)]
+    #[case(2, "43", false)]
+    fn test_check(#[case] part: u8, #[case] answer: &str, #[case] expected: bool) {
+        assert_eq!(ledger().check(part, answer).is_ok(), expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let ledger = ledger();
+        let ledger: Ledger = toml::from_str(&toml::to_string(&ledger).unwrap()).unwrap();
+        assert_eq!(ledger.correct_answer(2), Some("42"));
+        assert_eq!(ledger.submissions(1).count(), 3);
+    }
+}
@@ -0,0 +1,44 @@
+/// Converts the puzzle input into whichever of `&String` or `String` a legacy solution takes.
+pub trait FromInput<'a> {
+    #[allow(clippy::ptr_arg)]
+    fn from_input(input: &'a String) -> Self;
+}
+
+impl<'a> FromInput<'a> for &'a String {
+    fn from_input(input: &'a String) -> Self {
+        input
+    }
+}
+
+impl FromInput<'_> for String {
+    fn from_input(input: &String) -> Self {
+        input.clone()
+    }
+}
+
+/// Defines a `RawSolver` for a legacy solution made of free `solve_part_one` and `solve_part_two`
+/// functions in the current module, so that it can be registered like any other solver.
+macro_rules! legacy_solver {
+    ($year:literal, $day:literal) => {
+        pub struct Solver;
+
+        impl $crate::utils::v2::solver::RawSolver<$year, $day> for Solver {
+            type Part1 = String;
+            type Part2 = String;
+
+            fn solve_part_one(&self, input: &str) -> Self::Part1 {
+                use $crate::utils::v2::legacy::FromInput;
+                let input = input.to_owned();
+                solve_part_one(FromInput::from_input(&input)).to_string()
+            }
+
+            fn solve_part_two(&self, input: &str) -> Self::Part2 {
+                use $crate::utils::v2::legacy::FromInput;
+                let input = input.to_owned();
+                solve_part_two(FromInput::from_input(&input)).to_string()
+            }
+        }
+    };
+}
+
+pub(crate) use legacy_solver;
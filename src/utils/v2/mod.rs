@@ -1,4 +1,25 @@
+pub mod answer;
+pub mod bench;
+pub mod client;
+pub mod config;
 pub mod coords;
+pub mod examples;
+pub mod format;
 pub mod grid;
+pub mod legacy;
+pub mod leaderboard;
+pub mod ledger;
 pub mod parser;
+pub mod perf;
+pub mod progress;
+pub mod puzzle;
+pub mod registry;
+pub mod scaffold;
 pub mod solver;
+pub mod submit;
+pub mod summary;
+pub mod unlock;
+pub mod verify;
+pub mod visualize;
+pub mod watch;
+pub mod worker;
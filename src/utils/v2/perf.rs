@@ -0,0 +1,264 @@
+use std::{fs, path::PathBuf, process::Command, time::Duration};
+
+use ansi_term::{Colour, Style};
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use toml::value::Datetime;
+
+use crate::utils::v2::{
+    config::Profile,
+    format::{format_duration, toml_now},
+    solver::PartReport,
+};
+
+const TREND_LENGTH: usize = 10;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Run {
+    pub part: u8,
+    pub elapsed_ms: f64,
+    /// The commit the solver was built from, if it could be determined.
+    pub commit: Option<String>,
+    pub recorded_at: Datetime,
+}
+
+/// The timings of every run of a puzzle, stored in `perf/{year}/{day}.toml` in the directory of the
+/// profile.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default, rename = "run")]
+    runs: Vec<Run>,
+}
+
+impl History {
+    fn path(profile: &Profile, year: u32, day: u32) -> PathBuf {
+        profile.dir.join(format!("perf/{year}/{day:02}.toml"))
+    }
+
+    /// Loads the history of a puzzle, or an empty history if it has never been run.
+    pub fn load(profile: &Profile, year: u32, day: u32) -> Result<History> {
+        let path = History::path(profile, year, day);
+        if !path.exists() {
+            return Ok(History::default());
+        }
+        let history = toml::from_str(&fs::read_to_string(&path)?)
+            .map_err(|e| anyhow!("malformed performance history {}: {e}", path.display()))?;
+        Ok(history)
+    }
+
+    pub fn save(&self, profile: &Profile, year: u32, day: u32) -> Result<()> {
+        let path = History::path(profile, year, day);
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// Loads the history of every puzzle which has one, ordered by year then day.
+    pub fn load_all(profile: &Profile) -> Result<Vec<(u32, u32, History)>> {
+        let mut histories = Vec::new();
+        let Ok(years) = fs::read_dir(profile.dir.join("perf")) else {
+            return Ok(histories);
+        };
+        for year in years {
+            let year = year?;
+            let Some(y) = year.file_name().to_str().and_then(|y| y.parse().ok()) else {
+                continue;
+            };
+            for day in fs::read_dir(year.path())? {
+                let day = day?.path();
+                let Some(d) = day
+                    .file_stem()
+                    .and_then(|d| d.to_str())
+                    .and_then(|d| d.parse().ok())
+                else {
+                    continue;
+                };
+                histories.push((y, d, History::load(profile, y, d)?));
+            }
+        }
+        histories.sort_by_key(|(year, day, _)| (*year, *day));
+        Ok(histories)
+    }
+
+    /// Records the timing of each part which produced an answer.
+    pub fn record(&mut self, reports: &[PartReport], commit: Option<String>) {
+        let recorded_at = toml_now();
+        for report in reports.iter().filter(|report| report.answer.is_ok()) {
+            self.runs.push(Run {
+                part: report.part,
+                elapsed_ms: report.elapsed.as_secs_f64() * 1000.0,
+                commit: commit.clone(),
+                recorded_at,
+            });
+        }
+    }
+
+    pub fn runs(&self, part: u8) -> impl Iterator<Item = &Run> {
+        self.runs.iter().filter(move |run| run.part == part)
+    }
+
+    pub fn best(&self, part: u8) -> Option<&Run> {
+        self.runs(part)
+            .min_by(|a, b| a.elapsed_ms.total_cmp(&b.elapsed_ms))
+    }
+
+    /// How much slower the latest run of a part was than the best one, as a percentage.
+    pub fn slowdown(&self, part: u8) -> Option<f64> {
+        let latest = self.runs(part).last()?;
+        let best = self.best(part)?;
+        Some((latest.elapsed_ms / best.elapsed_ms - 1.0) * 100.0)
+    }
+}
+
+/// Returns the short hash of the checked out commit, marked as dirty if there are uncommitted
+/// changes, or `None` outside of a git repository.
+pub fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let hash = String::from_utf8(output.stdout).ok()?.trim().to_owned();
+    let clean = Command::new("git")
+        .args(["diff", "--quiet", "HEAD"])
+        .status()
+        .is_ok_and(|status| status.success());
+    Some(if clean { hash } else { format!("{hash}-dirty") })
+}
+
+/// Appends the timings of a run to the history of its puzzle.
+pub fn record(profile: &Profile, year: u32, day: u32, reports: &[PartReport]) -> Result<()> {
+    let mut history = History::load(profile, year, day)?;
+    history.record(reports, current_commit());
+    history.save(profile, year, day)
+}
+
+/// Draws the most recent timings as a sparkline, from fastest (▁) to slowest (█).
+fn sparkline(timings: &[f64]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let min = timings.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = timings.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    timings
+        .iter()
+        .map(|t| {
+            if max > min {
+                BARS[((t - min) / (max - min) * (BARS.len() - 1) as f64).round() as usize]
+            } else {
+                BARS[0]
+            }
+        })
+        .collect()
+}
+
+fn format_ms(ms: f64) -> String {
+    format_duration(Duration::from_secs_f64(ms / 1000.0))
+}
+
+/// Prints the trend of each part, highlighting those whose latest run was more than `threshold`
+/// percent slower than their best. Returns the number of such regressions.
+pub fn print_report(histories: &[(u32, u32, History)], threshold: f64) -> usize {
+    let header = format!(
+        "{:<7}  {:<4}  {:>4}  {:>9}  {:>9}  {:<TREND_LENGTH$}",
+        "Puzzle", "Part", "Runs", "Best", "Latest", "Trend"
+    );
+    println!("\n{}", Style::new().bold().paint(header));
+
+    let mut regressions = 0;
+    for (year, day, history) in histories {
+        for part in [1, 2] {
+            let runs = history.runs(part).collect::<Vec<_>>();
+            let (Some(best), Some(latest), Some(slowdown)) =
+                (history.best(part), runs.last(), history.slowdown(part))
+            else {
+                continue;
+            };
+            let recent = runs[runs.len().saturating_sub(TREND_LENGTH)..]
+                .iter()
+                .map(|run| run.elapsed_ms)
+                .collect::<Vec<_>>();
+            let row = format!(
+                "{:<7}  {:<4}  {:>4}  {:>9}  {:>9}  {:<TREND_LENGTH$}",
+                format!("{year}/{day:02}"),
+                part,
+                runs.len(),
+                format_ms(best.elapsed_ms),
+                format_ms(latest.elapsed_ms),
+                sparkline(&recent),
+            );
+            if slowdown > threshold {
+                regressions += 1;
+                let since = best.commit.as_deref().unwrap_or("an unknown commit");
+                println!(
+                    "{}",
+                    Colour::Red.paint(format!("{row}  ✖ {slowdown:.0}% slower than {since}"))
+                );
+            } else {
+                println!("{row}");
+            }
+        }
+    }
+
+    println!(
+        "\n{} parts got more than {}% slower than their best time",
+        regressions, threshold
+    );
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rstest::rstest;
+
+    use super::{sparkline, History};
+    use crate::utils::v2::solver::{PartReport, Status};
+
+    fn history(timings: &[u64]) -> History {
+        let mut history = History::default();
+        for &ms in timings {
+            let report = PartReport {
+                elapsed: Duration::from_millis(ms),
+                ..PartReport::solved(1, "42")
+            };
+            history.record(&[report], Some("abc1234".to_owned()));
+        }
+        history
+    }
+
+    #[rstest]
+    #[case(&[10, 8, 12], 50.0)]
+    #[case(&[10, 12, 8], 0.0)]
+    #[case(&[10], 0.0)]
+    fn test_slowdown(#[case] timings: &[u64], #[case] expected: f64) {
+        assert_eq!(history(timings).slowdown(1), Some(expected));
+        assert_eq!(history(timings).slowdown(2), None);
+    }
+
+    #[test]
+    fn test_failed_parts_are_not_recorded() {
+        let mut history = History::default();
+        let report = PartReport {
+            answer: Err("timed out".to_owned()),
+            elapsed: Duration::from_secs(10),
+            status: Status::TimedOut,
+            ..PartReport::solved(2, "")
+        };
+        history.record(&[report], None);
+        assert_eq!(history.runs(2).count(), 0);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let history = history(&[10, 8, 12]);
+        let history: History = toml::from_str(&toml::to_string(&history).unwrap()).unwrap();
+        assert_eq!(history.best(1).unwrap().elapsed_ms, 8.0);
+        assert_eq!(history.runs(1).count(), 3);
+    }
+
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[1.0, 8.0, 4.5, 1.0]), "▁█▅▁");
+        assert_eq!(sparkline(&[3.0, 3.0]), "▁▁");
+    }
+}
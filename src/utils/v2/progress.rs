@@ -0,0 +1,271 @@
+use std::collections::BTreeMap;
+
+use ansi_term::{Colour, Style};
+use anyhow::{bail, Result};
+use chrono::{Datelike, NaiveDate};
+
+use crate::utils::v2::{config::Profile, ledger::Ledger, perf::History, registry::Registry};
+
+const REPO_URL: &str = "https://github.com/jontmy/aoc-rust/blob/master";
+const FIRST_YEAR: u32 = 2015;
+
+pub const START_MARKER: &str = "<!-- progress:start -->";
+pub const END_MARKER: &str = "<!-- progress:end -->";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Day {
+    pub stars: u8,
+    pub has_solver: bool,
+}
+
+/// The stars earned on each day of every year since 2015.
+#[derive(Debug, Default)]
+pub struct Progress {
+    years: BTreeMap<u32, [Day; 25]>,
+}
+
+impl Progress {
+    /// Counts a star for each part whose answer was accepted according to the answer ledger, or
+    /// which produced an answer for the real input according to the timing history, up to and
+    /// including `latest_year`. Having a solver alone does not earn a star, since some solvers
+    /// only solve one part.
+    pub fn collect(registry: &Registry, profile: &Profile, latest_year: u32) -> Result<Progress> {
+        let mut progress = Progress::default();
+        for year in FIRST_YEAR..=latest_year {
+            let mut days = [Day::default(); 25];
+            for (day, entry) in (1..).zip(days.iter_mut()) {
+                let ledger = Ledger::load(profile, year, day)?;
+                let history = History::load(profile, year, day)?;
+                *entry = Day {
+                    stars: earned_stars(&ledger, &history),
+                    has_solver: registry.get(year, day).is_some(),
+                };
+            }
+            progress.years.insert(year, days);
+        }
+        Ok(progress)
+    }
+
+    pub fn stars(&self, year: u32) -> u32 {
+        self.years
+            .get(&year)
+            .map_or(0, |days| days.iter().map(|day| day.stars as u32).sum())
+    }
+
+    /// Years with at least one star, most recent first.
+    fn active_years(&self) -> impl Iterator<Item = (u32, &[Day; 25])> {
+        self.years
+            .iter()
+            .rev()
+            .map(|(year, days)| (*year, days))
+            .filter(|(year, _)| self.stars(*year) > 0)
+    }
+
+    /// Prints a calendar for each year with stars, with the stars of each day next to it.
+    pub fn print_calendars(&self) {
+        for (year, days) in self.active_years() {
+            println!(
+                "\n{}",
+                Style::new()
+                    .bold()
+                    .paint(format!("{year} ({}*)", self.stars(year)))
+            );
+            println!(
+                "{}",
+                Style::new()
+                    .dimmed()
+                    .paint("  S    M    T    W    T    F    S")
+            );
+            for week in calendar(year) {
+                let cells = week.iter().map(|day| match day {
+                    None => "     ".to_owned(),
+                    Some(day) => {
+                        let stars = days[*day as usize - 1].stars as usize;
+                        let stars = Colour::Yellow.paint(format!("{:<2}", "*".repeat(stars)));
+                        format!("{day:>3}{stars}")
+                    }
+                });
+                println!("{}", cells.collect::<String>().trim_end());
+            }
+        }
+    }
+
+    /// Renders the stars of every year as a table, followed by a calendar for each year with stars
+    /// linking to the solver of each day.
+    pub fn to_markdown(&self) -> String {
+        let years = self.years.keys().rev().collect::<Vec<_>>();
+        let mut markdown = String::new();
+        markdown.push_str(&table_row(
+            std::iter::once("Year".to_owned()).chain(years.iter().map(|y| y.to_string())),
+        ));
+        markdown.push_str(&table_row(std::iter::repeat_n(
+            ":-:".to_owned(),
+            years.len() + 1,
+        )));
+        markdown.push_str(&table_row(std::iter::once("Progress".to_owned()).chain(
+            years.iter().map(|&&year| match self.stars(year) {
+                0 => "`×`".to_owned(),
+                stars => format!("`{stars}*`"),
+            }),
+        )));
+
+        for (year, days) in self.active_years() {
+            markdown.push_str(&format!("\n### {year}\n\n"));
+            markdown.push_str("| S | M | T | W | T | F | S |\n");
+            markdown.push_str("|:-:|:-:|:-:|:-:|:-:|:-:|:-:|\n");
+            for week in calendar(year) {
+                let last = week.iter().rposition(Option::is_some).unwrap_or(0);
+                markdown.push_str(&table_row(week[..=last].iter().map(|day| match day {
+                    None => " ".to_owned(),
+                    Some(day) if days[*day as usize - 1].has_solver => {
+                        format!("[{day}]({REPO_URL}/src/aoc{year}/day{day:02}.rs)")
+                    }
+                    Some(day) => day.to_string(),
+                })));
+            }
+        }
+        markdown
+    }
+}
+
+fn earned_stars(ledger: &Ledger, history: &History) -> u8 {
+    [1, 2]
+        .into_iter()
+        .filter(|&part| {
+            ledger.correct_answer(part).is_some() || history.runs(part).next().is_some()
+        })
+        .count() as u8
+}
+
+/// The latest year whose puzzles have started unlocking.
+pub fn latest_year(today: NaiveDate) -> u32 {
+    let year = today.year() as u32;
+    if today.month() == 12 {
+        year
+    } else {
+        year - 1
+    }
+}
+
+/// Lays out the 25 days of December in weeks starting on Sunday.
+fn calendar(year: u32) -> Vec<[Option<u32>; 7]> {
+    let first = NaiveDate::from_ymd_opt(year as i32, 12, 1).expect("December 1st should exist");
+    let offset = first.weekday().num_days_from_sunday() as usize;
+    let mut weeks = Vec::new();
+    for (i, day) in (offset..).zip(1..=25) {
+        if i % 7 == 0 || weeks.is_empty() {
+            weeks.push([None; 7]);
+        }
+        weeks.last_mut().unwrap()[i % 7] = Some(day);
+    }
+    weeks
+}
+
+fn table_row(cells: impl Iterator<Item = String>) -> String {
+    format!("| {} |\n", cells.collect::<Vec<_>>().join(" | "))
+}
+
+/// Replaces everything between the progress markers in the README with `section`.
+pub fn replace_section(readme: &str, section: &str) -> Result<String> {
+    let (Some(start), Some(end)) = (readme.find(START_MARKER), readme.find(END_MARKER)) else {
+        bail!("the README should contain {START_MARKER} and {END_MARKER}");
+    };
+    if end < start {
+        bail!("{END_MARKER} should come after {START_MARKER}");
+    }
+    let start = start + START_MARKER.len();
+    Ok(format!(
+        "{}\n{}{}",
+        &readme[..start],
+        section,
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rstest::rstest;
+
+    use super::{calendar, earned_stars, latest_year, replace_section, Day, Progress};
+    use crate::utils::v2::{
+        ledger::Ledger,
+        perf::History,
+        solver::{PartReport, Status},
+        submit::Verdict,
+    };
+
+    #[test]
+    fn test_calendar() {
+        // December 1st, 2024 was a Sunday, and December 1st, 2022 a Thursday.
+        assert_eq!(calendar(2024)[0][0], Some(1));
+        assert_eq!(calendar(2024).len(), 4);
+        assert_eq!(calendar(2022)[0][4], Some(1));
+        assert_eq!(calendar(2022)[4][0], Some(25));
+    }
+
+    #[rstest]
+    #[case(2024, 11, 30, 2023)]
+    #[case(2024, 12, 1, 2024)]
+    #[case(2025, 1, 15, 2024)]
+    fn test_latest_year(#[case] y: i32, #[case] m: u32, #[case] d: u32, #[case] expected: u32) {
+        assert_eq!(
+            latest_year(NaiveDate::from_ymd_opt(y, m, d).unwrap()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_earned_stars() {
+        let mut ledger = Ledger::default();
+        let mut history = History::default();
+        assert_eq!(earned_stars(&ledger, &history), 0);
+
+        let unsolved = PartReport {
+            answer: Err("no answer".to_owned()),
+            status: Status::Failed,
+            ..PartReport::solved(2, "")
+        };
+        history.record(&[PartReport::solved(1, "41"), unsolved], None);
+        assert_eq!(earned_stars(&ledger, &history), 1);
+
+        ledger.record(2, "6", &Verdict::Correct);
+        assert_eq!(earned_stars(&ledger, &history), 2);
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let mut progress = Progress::default();
+        let mut days = [Day::default(); 25];
+        days[0] = Day {
+            stars: 2,
+            has_solver: true,
+        };
+        days[1] = Day {
+            stars: 1,
+            has_solver: false,
+        };
+        progress.years.insert(2024, days);
+        progress.years.insert(2023, [Day::default(); 25]);
+
+        let markdown = progress.to_markdown();
+        assert!(markdown.starts_with("| Year | 2024 | 2023 |\n|"));
+        assert!(markdown.contains("| Progress | `3*` | `×` |\n"));
+        assert!(markdown.contains("\n### 2024\n"));
+        assert!(!markdown.contains("### 2023"));
+        assert!(markdown.contains(
+            "| [1](https://github.com/jontmy/aoc-rust/blob/master/src/aoc2024/day01.rs) | 2 |"
+        ));
+        assert!(markdown.ends_with("| 22 | 23 | 24 | 25 |\n"));
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = "# AoC\n<!-- progress:start -->\nold\n<!-- progress:end -->\nfooter\n";
+        assert_eq!(
+            replace_section(readme, "new\n").unwrap(),
+            "# AoC\n<!-- progress:start -->\nnew\n<!-- progress:end -->\nfooter\n"
+        );
+        assert!(replace_section("# AoC\n", "new\n").is_err());
+    }
+}
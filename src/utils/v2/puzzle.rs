@@ -0,0 +1,166 @@
+use std::{fs, path::PathBuf};
+
+use ansi_term::{Colour, Style};
+use anyhow::Result;
+use once_cell_regex::regex;
+
+use crate::utils::v2::{client::Client, config::Profile, ledger::Ledger};
+
+fn path(profile: &Profile, year: u32, day: u32) -> PathBuf {
+    profile.dir.join(format!("input/{year}/{day:02}.html"))
+}
+
+/// Reads the puzzle page from the cache, downloading it if it is missing, if `refetch` is set, or
+/// if part 1 has been solved since it was cached (so that part 2 is included).
+pub fn fetch_page(profile: &Profile, year: u32, day: u32, refetch: bool) -> Result<String> {
+    let path = path(profile, year, day);
+    if path.exists() && !refetch {
+        let html = fs::read_to_string(&path)?;
+        let has_part_two = articles(&html).len() > 1;
+        if has_part_two
+            || Ledger::load(profile, year, day)?
+                .correct_answer(1)
+                .is_none()
+        {
+            return Ok(html);
+        }
+    }
+
+    let html = Client::for_profile(profile)?.get(&format!("/{year}/day/{day}"))?;
+    fs::create_dir_all(path.parent().unwrap())?;
+    fs::write(&path, &html)?;
+    Ok(html)
+}
+
+/// Reads the puzzle page from the cache only, without downloading it.
+pub fn cached_page(profile: &Profile, year: u32, day: u32) -> Option<String> {
+    fs::read_to_string(path(profile, year, day)).ok()
+}
+
+/// Extracts the description of each part from a puzzle page.
+pub fn articles(html: &str) -> Vec<&str> {
+    regex!(r#"(?s)<article class="day-desc">(.*?)</article>"#)
+        .captures_iter(html)
+        .map(|captures| captures.get(1).unwrap().as_str())
+        .collect()
+}
+
+pub fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Renders the puzzle description as terminal text, styling emphasis, inline code and code blocks.
+pub fn render(html: &str) -> String {
+    articles(html)
+        .into_iter()
+        .map(render_article)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_article(article: &str) -> String {
+    let tag = regex!(r"<(/?)(\w+)[^>]*>");
+    let (mut em, mut code, mut heading, mut pre) = (0, 0, 0, false);
+    let mut rendered = String::new();
+    let mut last = 0;
+
+    for captures in tag.captures_iter(article) {
+        let whole = captures.get(0).unwrap();
+        let text = unescape(&article[last..whole.start()]);
+        last = whole.end();
+
+        let mut style = Style::new();
+        if heading > 0 || em > 0 {
+            style = style.bold();
+        }
+        if code > 0 || pre {
+            style = style.fg(Colour::Cyan);
+        } else if em > 0 {
+            style = style.fg(Colour::White);
+        }
+
+        if pre {
+            // Indent code blocks, styling each line separately so that the indent stays unstyled.
+            for (i, line) in text.split('\n').enumerate() {
+                if i > 0 {
+                    rendered.push_str("\n    ");
+                }
+                if !line.is_empty() {
+                    rendered.push_str(&style.paint(line).to_string());
+                }
+            }
+        } else {
+            // Whitespace between block elements is insignificant.
+            let text = text.replace('\n', " ");
+            let at_line_start = rendered.is_empty() || rendered.ends_with('\n');
+            if !(text.trim().is_empty() && at_line_start) {
+                rendered.push_str(&style.paint(text).to_string());
+            }
+        }
+
+        let closing = &captures[1] == "/";
+        let delta = if closing { -1 } else { 1 };
+        match &captures[2] {
+            "em" => em += delta,
+            "code" => code += delta,
+            "h2" => {
+                heading += delta;
+                if closing {
+                    rendered.push_str("\n\n");
+                }
+            }
+            "pre" if closing => {
+                pre = false;
+                rendered = rendered.trim_end_matches([' ', '\n']).to_owned();
+                rendered.push_str("\n\n");
+            }
+            "pre" => {
+                pre = true;
+                rendered.push_str("    ");
+            }
+            "p" if closing => rendered.push_str("\n\n"),
+            "li" if closing => rendered.push('\n'),
+            "li" => rendered.push_str("  • "),
+            "ul" if closing => rendered.push('\n'),
+            _ => {}
+        }
+    }
+    rendered.push_str(&unescape(&article[last..]));
+    rendered.trim_end().to_owned() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell_regex::regex;
+
+    use super::render;
+
+    #[test]
+    fn test_render() {
+        let html = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>Pair up the <em>smallest</em> number with <code>3 &lt; 4</code>:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<ul><li>One.</li><li>Two.</li></ul>
+</article>
+<p>Answer: <input type="text" name="answer"/></p>
+</main>"#;
+        let rendered = render(html);
+        let plain = regex!(r"\x1b\[[0-9;]*m").replace_all(&rendered, "");
+        assert_eq!(
+            plain,
+            "--- Day 1: Historian Hysteria ---\n\n\
+             Pair up the smallest number with 3 < 4:\n\n    \
+             3   4\n    \
+             4   3\n\n  \
+             • One.\n  \
+             • Two.\n"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use std::{collections::BTreeMap, hint::black_box, sync::Arc};
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::utils::v2::{
+    answer::Answer,
+    bench::BenchReport,
+    solver::{InputSource, PartReport, SolveOptions, Solver},
+};
+
+/// A type-erased [`Solver`], so that solvers for different puzzles can be stored side by side.
+pub trait DynSolver: Send + Sync {
+    fn year(&self) -> u32;
+    fn day(&self) -> u32;
+    /// Parses the input and solves one part on the current thread, without any output.
+    fn answer(&self, part: u8, input: &str) -> Result<String>;
+    fn solve(&self, options: &SolveOptions) -> Result<Vec<PartReport>>;
+    /// Solves one part of the puzzle for an example input.
+    fn solve_part(&self, part: u8, input: &str) -> Result<PartReport>;
+    /// Solves the puzzle for an input which is not the cached one, without checking the answers
+    /// against the ledger or recording the timings.
+    fn solve_input(
+        &self,
+        input: &str,
+        source: InputSource,
+        options: &SolveOptions,
+    ) -> Result<Vec<PartReport>>;
+    fn bench(&self, options: &SolveOptions, runs: usize) -> Result<BenchReport>;
+    fn steps<'a>(&'a self, input: &'a str) -> Result<Vec<Step<'a>>>;
+}
+
+/// One step of solving a puzzle (parsing, or one of the parts), which can be run repeatedly.
+pub struct Step<'a> {
+    pub name: &'static str,
+    pub run: Box<dyn FnMut() + 'a>,
+}
+
+struct Registered<S, const YEAR: u32, const DAY: u32>(Arc<S>);
+
+impl<S, const YEAR: u32, const DAY: u32> DynSolver for Registered<S, YEAR, DAY>
+where
+    S: Solver<YEAR, DAY>,
+{
+    fn year(&self) -> u32 {
+        YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn answer(&self, part: u8, input: &str) -> Result<String> {
+        let input = self.0.parse(input)?;
+        let answer = match part {
+            1 => self.0.solve_part_one(&input).submission(),
+            2 => self.0.solve_part_two(&input).submission(),
+            _ => bail!("there is no part {part}"),
+        };
+        answer.map_err(|e| anyhow!(e))
+    }
+
+    fn solve(&self, options: &SolveOptions) -> Result<Vec<PartReport>> {
+        Arc::clone(&self.0).solve(options)
+    }
+
+    fn solve_part(&self, part: u8, input: &str) -> Result<PartReport> {
+        let input = Arc::new(self.0.parse(input)?);
+        let options = SolveOptions::default();
+        Arc::clone(&self.0).solve_part(part, input, InputSource::Example, None, &options)
+    }
+
+    fn solve_input(
+        &self,
+        input: &str,
+        source: InputSource,
+        options: &SolveOptions,
+    ) -> Result<Vec<PartReport>> {
+        let input = Arc::new(self.0.parse_input(input, options)?);
+        options
+            .parts()
+            .into_iter()
+            .map(|part| {
+                Arc::clone(&self.0).solve_part(part, Arc::clone(&input), source, None, options)
+            })
+            .collect()
+    }
+
+    fn bench(&self, options: &SolveOptions, runs: usize) -> Result<BenchReport> {
+        self.0.bench(options, runs)
+    }
+
+    fn steps<'a>(&'a self, input: &'a str) -> Result<Vec<Step<'a>>> {
+        let solver = &self.0;
+        let parsed = Arc::new(solver.parse(input)?);
+        let parsed_for_part_two = Arc::clone(&parsed);
+        Ok(vec![
+            Step {
+                name: "parse",
+                run: Box::new(move || {
+                    let _ = black_box(solver.parse(input));
+                }),
+            },
+            Step {
+                name: "part1",
+                run: Box::new(move || {
+                    black_box(solver.solve_part_one(&parsed));
+                }),
+            },
+            Step {
+                name: "part2",
+                run: Box::new(move || {
+                    black_box(solver.solve_part_two(&parsed_for_part_two));
+                }),
+            },
+        ])
+    }
+}
+
+/// All known solvers, keyed by `(year, day)`.
+#[derive(Default)]
+pub struct Registry {
+    solvers: BTreeMap<(u32, u32), Box<dyn DynSolver>>,
+}
+
+impl Registry {
+    /// Registers a solver under the year and day given by its [`Solver`] implementation.
+    pub fn register<S, const YEAR: u32, const DAY: u32>(&mut self, solver: S)
+    where
+        S: Solver<YEAR, DAY>,
+    {
+        let previous = self.solvers.insert(
+            (YEAR, DAY),
+            Box::new(Registered::<S, YEAR, DAY>(Arc::new(solver))),
+        );
+        assert!(
+            previous.is_none(),
+            "solver for day {DAY} of {YEAR} registered twice"
+        );
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Option<&dyn DynSolver> {
+        self.solvers.get(&(year, day)).map(|solver| solver.as_ref())
+    }
+
+    /// Iterates over every registered solver, ordered by year then day.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn DynSolver> {
+        self.solvers.values().map(|solver| solver.as_ref())
+    }
+
+    pub fn years(&self) -> Vec<u32> {
+        let mut years = self
+            .solvers
+            .keys()
+            .map(|(year, _)| *year)
+            .collect::<Vec<_>>();
+        years.dedup();
+        years
+    }
+
+    pub fn days(&self, year: u32) -> impl Iterator<Item = u32> + '_ {
+        self.solvers
+            .keys()
+            .filter(move |(y, _)| *y == year)
+            .map(|(_, day)| *day)
+    }
+}
+
+/// Generates a `register` function for a year module, which adds the `Solver` of each listed day
+/// module to a [`Registry`]. The year and day are inferred from each `Solver` implementation.
+macro_rules! register_solvers {
+    ($($day:ident),* $(,)?) => {
+        pub fn register(registry: &mut $crate::utils::v2::registry::Registry) {
+            $(registry.register($day::Solver);)*
+        }
+    };
+}
+
+pub(crate) use register_solvers;
@@ -0,0 +1,279 @@
+use std::{fs, path::Path};
+
+use anyhow::{anyhow, bail, Context, Result};
+use once_cell_regex::regex;
+
+const DAY_TEMPLATE: &str = include_str!("templates/day.rs");
+const MAX_WIDTH: usize = 100;
+
+/// Generates `src/aoc{year}/day{day}.rs` from the template and registers it, creating the year
+/// module if it does not exist yet. Returns the path of the generated file.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<String> {
+    let lib_path = root.join("src/lib.rs");
+    let year_dir = root.join(format!("src/aoc{year}"));
+    let mod_path = year_dir.join("mod.rs");
+    let day_path = year_dir.join(format!("day{day:02}.rs"));
+
+    if !lib_path.exists() {
+        bail!(
+            "{} not found, run this from the repository root",
+            lib_path.display()
+        );
+    }
+    if day_path.exists() {
+        bail!("{} already exists", day_path.display());
+    }
+
+    // Everything is worked out before anything is written, and lib.rs is written last, so that a
+    // failure part way through never leaves lib.rs declaring a year module which does not exist.
+    let (year_module, lib) = match fs::read_to_string(&mod_path) {
+        Ok(year_module) => (year_module, None),
+        Err(_) => {
+            let lib = fs::read_to_string(&lib_path)?;
+            let year_module =
+                "use crate::utils::v2::registry::register_solvers;\n\nregister_solvers!();\n";
+            (year_module.to_owned(), Some(add_year(&lib, year)?))
+        }
+    };
+    let year_module = add_day(&year_module, day)?;
+    let day_source = DAY_TEMPLATE
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{DAY}}", &day.to_string());
+
+    fs::create_dir_all(&year_dir)?;
+    fs::write(&day_path, day_source)
+        .with_context(|| format!("failed to write {}", day_path.display()))?;
+    fs::write(&mod_path, year_module)?;
+    if let Some(lib) = lib {
+        fs::write(&lib_path, lib)?;
+    }
+
+    Ok(day_path.display().to_string())
+}
+
+/// Declares `pub mod day{day}` in a year module and adds it to its `register_solvers!` list.
+fn add_day(year_module: &str, day: u32) -> Result<String> {
+    let module = format!("day{day:02}");
+    let invocation = regex!(r"(?s)register_solvers!\((.*?)\);\n?");
+    let captures = invocation
+        .captures(year_module)
+        .ok_or_else(|| anyhow!("year module does not call register_solvers!"))?;
+
+    let mut days = captures[1]
+        .split(',')
+        .map(str::trim)
+        .filter(|day| !day.is_empty())
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+    days.push(module.clone());
+    days.sort();
+    let invocation = format_invocation(&days);
+
+    let without_invocation = invocation_removed(year_module, captures.get(0).unwrap().range());
+    let mut lines = without_invocation
+        .lines()
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+    let declaration = format!("pub mod {module};");
+    let position = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod day") && **line > *declaration)
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| line.starts_with("pub mod day"))
+                .map(|i| i + 1)
+        });
+    match position {
+        Some(i) => lines.insert(i, declaration),
+        None => {
+            // The first day of a year goes after the imports.
+            while lines.last().is_some_and(|line| line.is_empty()) {
+                lines.pop();
+            }
+            lines.push(String::new());
+            lines.push(declaration);
+        }
+    }
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    Ok(format!("{}\n\n{invocation}\n", lines.join("\n")))
+}
+
+fn invocation_removed(source: &str, range: std::ops::Range<usize>) -> String {
+    format!("{}{}", &source[..range.start], &source[range.end..])
+}
+
+/// Formats the `register_solvers!` call the way rustfmt would.
+fn format_invocation(days: &[String]) -> String {
+    let single_line = format!("register_solvers!({});", days.join(", "));
+    if single_line.len() <= MAX_WIDTH {
+        return single_line;
+    }
+
+    let mut lines = vec![String::from("   ")];
+    for day in days {
+        let line = lines.last_mut().unwrap();
+        if line.len() + day.len() + 2 > MAX_WIDTH {
+            lines.push(String::from("   "));
+        }
+        let line = lines.last_mut().unwrap();
+        line.push(' ');
+        line.push_str(day);
+        line.push(',');
+    }
+    let mut body = lines.join("\n");
+    body.pop();
+    format!("register_solvers!(\n{body}\n);")
+}
+
+/// Declares `mod aoc{year}` in `lib.rs` and registers its solvers.
+fn add_year(lib: &str, year: u32) -> Result<String> {
+    let declaration = format!("mod aoc{year};");
+    let registration = format!("    aoc{year}::register(&mut registry);");
+    if lib.contains(&declaration) {
+        bail!("aoc{year} is already declared in lib.rs");
+    }
+
+    let mut lines = lib.lines().map(str::to_owned).collect::<Vec<_>>();
+    for (line, prefix) in [(declaration, "mod aoc"), (registration, "    aoc")] {
+        let position = lines
+            .iter()
+            .position(|l| l.starts_with(prefix) && *l > line)
+            .or_else(|| {
+                lines
+                    .iter()
+                    .rposition(|l| l.starts_with(prefix))
+                    .map(|i| i + 1)
+            })
+            .ok_or_else(|| anyhow!("could not find where to add aoc{year} in lib.rs"))?;
+        lines.insert(position, line);
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use indoc::indoc;
+
+    use super::{add_day, add_year, new_day};
+
+    #[test]
+    fn test_add_day() {
+        let year_module = indoc! {"
+            use crate::utils::v2::registry::register_solvers;
+
+            pub mod day01;
+            pub mod day03;
+
+            register_solvers!(day01, day03);
+        "};
+        let expected = indoc! {"
+            use crate::utils::v2::registry::register_solvers;
+
+            pub mod day01;
+            pub mod day02;
+            pub mod day03;
+
+            register_solvers!(day01, day02, day03);
+        "};
+        assert_eq!(add_day(year_module, 2).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_add_first_day() {
+        let year_module = indoc! {"
+            use crate::utils::v2::registry::register_solvers;
+
+            register_solvers!();
+        "};
+        let expected = indoc! {"
+            use crate::utils::v2::registry::register_solvers;
+
+            pub mod day13;
+
+            register_solvers!(day13);
+        "};
+        assert_eq!(add_day(year_module, 13).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_add_day_wraps_long_invocation() {
+        let days = (1..=12).map(|d| format!("day{d:02}")).collect::<Vec<_>>();
+        let year_module = format!(
+            "{}\n\nregister_solvers!(\n    {}\n);\n",
+            days.iter()
+                .map(|d| format!("pub mod {d};"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            days.join(", ")
+        );
+        let result = add_day(&year_module, 13).unwrap();
+        assert!(result.ends_with(indoc! {"
+            register_solvers!(
+                day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13
+            );
+        "}));
+    }
+
+    #[test]
+    fn test_add_year() {
+        let lib = indoc! {"
+            mod aoc2022;
+            mod aoc2024;
+            pub mod utils;
+
+            pub fn registry() -> Registry {
+                let mut registry = Registry::default();
+                aoc2022::register(&mut registry);
+                aoc2024::register(&mut registry);
+                registry
+            }
+        "};
+        let expected = indoc! {"
+            mod aoc2022;
+            mod aoc2023;
+            mod aoc2024;
+            pub mod utils;
+
+            pub fn registry() -> Registry {
+                let mut registry = Registry::default();
+                aoc2022::register(&mut registry);
+                aoc2023::register(&mut registry);
+                aoc2024::register(&mut registry);
+                registry
+            }
+        "};
+        assert_eq!(add_year(lib, 2023).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_new_day_leaves_lib_untouched_on_failure() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let lib = indoc! {"
+            mod aoc2024;
+            pub mod utils;
+
+            pub fn registry() -> Registry {
+                let mut registry = Registry::default();
+                aoc2024::register(&mut registry);
+                registry
+            }
+        "};
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/lib.rs"), lib).unwrap();
+        // A file where the year directory should be makes creating the day fail.
+        fs::write(root.join("src/aoc2025"), "").unwrap();
+
+        let result = new_day(&root, 2025, 1);
+        let lib_after = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(result.is_err());
+        assert_eq!(lib_after, lib);
+    }
+}
@@ -1,67 +1,203 @@
-use spinners::{Spinner, Spinners};
+use spinners::{Spinner, Spinners, Stream};
 use std::{
     error::Error,
-    fmt::Debug,
     fs::{self, File, OpenOptions},
-    io::Write,
+    io::{self, Write},
     os,
-    path::Path,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
 };
 
-use ansi_term::Style;
-use anyhow::{Context, Result};
+use ansi_term::{Colour, Style};
+use anyhow::{anyhow, Context, Result};
 use dotenv;
 
+use crate::utils::v2::{
+    answer::Answer,
+    bench::{self, BenchReport},
+    client::Client,
+    config::Profile,
+    ledger::Ledger,
+    perf, visualize,
+    worker::{self, Outcome},
+};
+
+/// Where the input of a puzzle is cached: `input/{year}/{day}.txt` in the directory of the profile,
+/// or with the given extension instead of `txt` for examples.
+pub fn input_path(profile: &Profile, year: u32, day: u32, file_extension: Option<&str>) -> PathBuf {
+    let ext = file_extension.unwrap_or("txt");
+    profile.dir.join(format!("input/{year}/{day:02}.{ext}"))
+}
+
+/// Where the input of a puzzle came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InputSource {
     File,
     Web,
+    Stdin,
+    /// An example from the puzzle description.
+    Example,
 }
 
-pub trait Solver<const YEAR: u32, const DAY: u32> {
-    type Part1: Debug;
-    type Part2: Debug;
+impl InputSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InputSource::File => "file",
+            InputSource::Web => "web",
+            InputSource::Stdin => "stdin",
+            InputSource::Example => "example",
+        }
+    }
+}
 
-    fn solve_part_one(&self, input: &str) -> Self::Part1;
-    fn solve_part_two(&self, input: &str) -> Self::Part2;
+/// How solving a part ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    /// The answer differs from the one which was accepted.
+    Regressed,
+    Failed,
+    Panicked,
+    TimedOut,
+}
 
-    fn fetch_input(
-        &self,
-        refetch: bool,
-        file_extension: Option<&str>,
-    ) -> Result<(String, InputSource)> {
-        dotenv::dotenv().ok();
-        let session_token = dotenv::var("SESSION_TOKEN")
-            .with_context(|| "environment variable SESSION_TOKEN should be set")?;
-
-        let filename = if let Some(ext) = file_extension {
-            format!("input/{YEAR}/{DAY:02}.{ext}")
-        } else {
-            format!("input/{YEAR}/{DAY:02}.txt")
-        };
-        let url = format!("https://adventofcode.com/{YEAR}/day/{DAY}/input");
-        let path = Path::new(&filename);
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Regressed => "regressed",
+            Status::Failed => "failed",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed_out",
+        }
+    }
+}
 
-        if path.exists() && !refetch {
-            let input = fs::read_to_string(path)?.trim().into();
-            return Ok((input, InputSource::File));
+/// The answer to one part of a puzzle (or why it has none), and how long it took to compute.
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+    pub source: InputSource,
+    pub status: Status,
+}
+
+impl PartReport {
+    /// Whether the part produced an answer which does not contradict the accepted one.
+    pub fn is_solved(&self) -> bool {
+        self.answer.is_ok() && self.status != Status::Regressed
+    }
+
+    /// A part of the real input solved instantly, for tests to adjust as needed.
+    #[cfg(test)]
+    pub(crate) fn solved(part: u8, answer: &str) -> PartReport {
+        PartReport {
+            part,
+            answer: Ok(answer.to_owned()),
+            elapsed: Duration::ZERO,
+            source: InputSource::File,
+            status: Status::Solved,
+        }
+    }
+}
+
+/// How to fetch the input of a puzzle and run its solver.
+#[derive(Debug, Clone, Default)]
+pub struct SolveOptions {
+    /// Download the input again even if it is cached.
+    pub refetch: bool,
+    /// Read the input from `input/{year}/{day}.{ext}` instead, e.g. for examples.
+    pub file_extension: Option<String>,
+    /// Read the input from this file instead, or from stdin if it is `-`. It is never cached.
+    pub input_path: Option<PathBuf>,
+    /// Solve only this part instead of both.
+    pub part: Option<u8>,
+    /// Whose input to fetch, and where to cache it.
+    pub profile: Profile,
+    /// Give up on a part after this long.
+    pub timeout: Option<Duration>,
+    /// Keep stdout free of headers and spinners. Failures are still reported on stderr.
+    pub quiet: bool,
+}
+
+impl SolveOptions {
+    /// Whether the real puzzle input is used, as opposed to an example or a file given by hand.
+    /// Accepted answers and timings only apply to the real input.
+    pub fn is_real_input(&self) -> bool {
+        self.file_extension.is_none() && self.input_path.is_none()
+    }
+
+    /// The parts to solve, both unless `part` is set.
+    pub fn parts(&self) -> Vec<u8> {
+        self.part.map_or(vec![1, 2], |part| vec![part])
+    }
+}
+
+/// A spinner, or nothing at all in quiet mode.
+struct Progress(Option<Spinner>);
+
+impl Progress {
+    fn new(options: &SolveOptions, message: String) -> Self {
+        Progress((!options.quiet).then(|| Spinner::new(Spinners::Dots, message)))
+    }
+
+    fn succeed(&mut self, message: String) {
+        if let Some(spinner) = &mut self.0 {
+            spinner.stop_and_persist("✔", message);
         }
+    }
 
-        let client = reqwest::blocking::Client::new();
-        let response = client
-            .get(&url)
-            .header("Cookie", format!("session={}", session_token))
-            .header(
-                "User-Agent",
-                "https://github.com/jontmy/aoc-rust/blob/master/src/utils/v2/aoc.rs by jontmy",
-            )
-            .build()?;
-
-        let input = client.execute(response).and_then(|res| res.text())?;
-        if input.starts_with("Please don't repeatedly request this endpoint before it unlocks!") {
-            eprintln!("Please wait until the puzzle unlocks before fetching the input.");
-            std::process::exit(1);
+    fn fail(&mut self, message: String) {
+        match &mut self.0 {
+            Some(spinner) => spinner.stop_and_persist(
+                &Colour::Red.paint("✖").to_string(),
+                Colour::Red.paint(message).to_string(),
+            ),
+            None => eprintln!("✖ {message}"),
         }
+    }
+}
+
+/// Solvers run each part on a worker thread, so they and their parsed input must be shareable
+/// across threads.
+pub trait Solver<const YEAR: u32, const DAY: u32>: Send + Sync + 'static {
+    /// The parsed puzzle input, shared by both parts.
+    type Input: Send + Sync + 'static;
+    type Part1: Answer;
+    type Part2: Answer;
 
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn solve_part_one(&self, input: &Self::Input) -> Self::Part1;
+    fn solve_part_two(&self, input: &Self::Input) -> Self::Part2;
+
+    fn fetch_input(&self, options: &SolveOptions) -> Result<(String, InputSource)> {
+        if let Some(path) = &options.input_path {
+            if path == Path::new("-") {
+                let input = io::read_to_string(io::stdin()).context("failed to read stdin")?;
+                return Ok((input.trim().into(), InputSource::Stdin));
+            }
+            let input = fs::read_to_string(path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            return Ok((input.trim().into(), InputSource::File));
+        }
+
+        let path = input_path(
+            &options.profile,
+            YEAR,
+            DAY,
+            options.file_extension.as_deref(),
+        );
+        let path = path.as_path();
+
+        if path.exists() && !options.refetch {
+            let input = fs::read_to_string(path)?.trim().into();
+            return Ok((input, InputSource::File));
+        }
+
+        // Only successful responses are cached, never error pages.
+        let input =
+            Client::for_profile(&options.profile)?.get(&format!("/{YEAR}/day/{DAY}/input"))?;
         let input = input.trim();
         fs::create_dir_all(path.parent().unwrap())?;
         fs::write(path, input)?;
@@ -69,68 +205,196 @@ pub trait Solver<const YEAR: u32, const DAY: u32> {
         Ok((input.trim().into(), InputSource::Web))
     }
 
-    fn solve_part(&self, part: u8, input: &str) -> Result<()> {
-        let mut spinner = Spinner::new(Spinners::Dots, format!("Solving part {}...", part));
+    /// Parses the puzzle input once for both parts, timing it separately from either part.
+    fn parse_input(&self, input: &str, options: &SolveOptions) -> Result<Self::Input> {
+        let mut progress = Progress::new(options, "Parsing input...".into());
+        let tick = std::time::Instant::now();
+        match self.parse(input) {
+            Ok(input) => {
+                progress.succeed(format!(
+                    "Input parsed in {:.1}ms",
+                    tick.elapsed().as_secs_f64() * 1000.0
+                ));
+                Ok(input)
+            }
+            Err(e) => {
+                progress.fail(format!("Failed to parse input: {}", e));
+                Err(e)
+            }
+        }
+    }
+
+    /// Solves one part of the puzzle on a worker thread, giving up after the timeout, and flagging
+    /// a regression if the answer differs from `expected`.
+    fn solve_part(
+        self: Arc<Self>,
+        part: u8,
+        input: Arc<Self::Input>,
+        source: InputSource,
+        expected: Option<&str>,
+        options: &SolveOptions,
+    ) -> Result<PartReport> {
+        let mut progress = Progress::new(options, format!("Solving part {}...", part));
         let tick = std::time::Instant::now();
 
-        let mut answer = match part {
-            1 => format!("{:?}", self.solve_part_one(input)),
-            2 => format!("{:?}", self.solve_part_two(input)),
+        let name = format!("{YEAR}/{DAY:02} part {part}");
+        let outcome = worker::run(name, options.timeout, move || match part {
+            1 => self.solve_part_one(&input).submission(),
+            2 => self.solve_part_two(&input).submission(),
             _ => unreachable!(),
+        });
+        let (answer, status) = match outcome {
+            Outcome::Finished(Ok(answer)) if expected.is_some_and(|e| e != answer) => {
+                (Ok(answer), Status::Regressed)
+            }
+            Outcome::Finished(Ok(answer)) => (Ok(answer), Status::Solved),
+            Outcome::Finished(Err(e)) => (Err(e), Status::Failed),
+            Outcome::Panicked(message) => (Err(format!("panicked: {message}")), Status::Panicked),
+            Outcome::TimedOut => (Err("timed out".to_owned()), Status::TimedOut),
         };
 
-        // Hack to allow Result types to be printed without the Ok/Err prefix
-        if answer.starts_with("Ok(") {
-            answer = answer
-                .trim_start_matches("Ok(")
-                .trim_end_matches(")")
-                .to_owned();
-        }
-        if answer.starts_with("Err(") {
-            answer = answer
-                .trim_start_matches("Err(")
-                .trim_end_matches(")")
-                .to_owned();
-        }
-
-        let elapsed = tick.elapsed().as_secs_f64() * 1000.0;
-        spinner.stop_and_persist(
-            "✔",
-            format!(
+        let elapsed = tick.elapsed();
+        let ms = elapsed.as_secs_f64() * 1000.0;
+        match &answer {
+            Err(e) => progress.fail(format!("Part {} failed in {:.1}ms ({})", part, ms, e)),
+            Ok(answer) if status == Status::Regressed => progress.fail(format!(
+                "Part {} regressed in {:.1}ms (answer: {}, but {} was accepted)",
+                part,
+                ms,
+                answer,
+                expected.unwrap()
+            )),
+            Ok(answer) => progress.succeed(format!(
                 "Part {} solved in {:.1}ms (answer: {})",
-                part, elapsed, answer
-            ),
-        );
-        Ok(())
+                part, ms, answer
+            )),
+        }
+        Ok(PartReport {
+            part,
+            answer,
+            elapsed,
+            source,
+            status,
+        })
     }
 
-    fn solve(&self, refetch: bool, file_extension: Option<&str>) -> Result<()> {
-        println!(
-            "\n{}",
-            ansi_term::Style::new()
-                .bold()
-                .paint(format!("Advent of Code {YEAR}, Day {DAY}"))
-        );
+    fn solve(self: Arc<Self>, options: &SolveOptions) -> Result<Vec<PartReport>> {
+        if !options.quiet {
+            println!(
+                "\n{}",
+                ansi_term::Style::new()
+                    .bold()
+                    .paint(match &options.profile.name {
+                        Some(name) => format!("Advent of Code {YEAR}, Day {DAY} ({name})"),
+                        None => format!("Advent of Code {YEAR}, Day {DAY}"),
+                    })
+            );
+        }
 
-        let mut spinner = Spinner::new(Spinners::Dots, "Fetching input...".into());
-        match self.fetch_input(refetch, file_extension) {
+        let mut progress = Progress::new(options, "Fetching input...".into());
+        match self.fetch_input(options) {
             Ok((input, source)) => {
-                match source {
-                    InputSource::File => {
-                        (&mut spinner).stop_and_persist("✔", "Input read from cache".into())
+                match (source, &options.input_path) {
+                    (InputSource::Web, _) => {
+                        progress.succeed("Input downloaded successfully".into())
                     }
-                    InputSource::Web => {
-                        spinner.stop_and_persist("✔", "Input downloaded successfully".into())
+                    (InputSource::Stdin, _) => progress.succeed("Input read from stdin".into()),
+                    (_, Some(path)) => {
+                        progress.succeed(format!("Input read from {}", path.display()))
                     }
+                    _ => progress.succeed("Input read from cache".into()),
+                }
+                let ledger = match options.is_real_input() {
+                    true => Ledger::load(&options.profile, YEAR, DAY)?,
+                    false => Ledger::default(),
+                };
+                let input = Arc::new(self.parse_input(&input, options)?);
+                let mut reports = Vec::new();
+                for part in options.parts() {
+                    reports.push(Arc::clone(&self).solve_part(
+                        part,
+                        Arc::clone(&input),
+                        source,
+                        ledger.correct_answer(part),
+                        options,
+                    )?);
                 }
-                self.solve_part(1, &input)?;
-                self.solve_part(2, &input)?;
-                Ok(())
+                // Recording frames slows solvers down, so those timings would look like regressions.
+                if options.is_real_input() && !visualize::is_recording() {
+                    if let Err(e) = perf::record(&options.profile, YEAR, DAY, &reports) {
+                        eprintln!("Failed to record the timings: {e}");
+                    }
+                }
+                Ok(reports)
             }
             Err(e) => {
-                spinner.stop_and_persist("✖", format!("Failed to fetch input: {}", e));
-                Err(e.into())
+                progress.fail(format!("Failed to fetch input: {}", e));
+                Err(e)
             }
         }
     }
+
+    /// Times parsing the input and solving each part over `runs` runs. Progress goes to stderr so
+    /// that the report can be piped. A panic fails the benchmark instead of aborting the run.
+    fn bench(&self, options: &SolveOptions, runs: usize) -> Result<BenchReport> {
+        let mut spinner = Spinner::with_stream(
+            Spinners::Dots,
+            format!("Benchmarking {YEAR}/{DAY:02} over {runs} runs..."),
+            Stream::Stderr,
+        );
+        let report = self.fetch_input(options).and_then(|(input, _)| {
+            worker::catch(|| {
+                let parsed = self.parse(&input)?;
+                Ok(BenchReport {
+                    year: YEAR,
+                    day: DAY,
+                    runs,
+                    parse: bench::measure(runs, || self.parse(&input)),
+                    parts: vec![
+                        bench::measure(runs, || self.solve_part_one(&parsed)),
+                        bench::measure(runs, || self.solve_part_two(&parsed)),
+                    ],
+                })
+            })
+            .unwrap_or_else(|message| Err(anyhow!("panicked: {message}")))
+        });
+        match &report {
+            Ok(_) => spinner.stop_and_persist("✔", format!("Benchmarked {YEAR}/{DAY:02}")),
+            Err(e) => {
+                spinner.stop_and_persist("✖", format!("Failed to benchmark {YEAR}/{DAY:02}: {e}"))
+            }
+        }
+        report
+    }
+}
+
+/// A solver that works on the raw puzzle input, for puzzles where a separate parse step does not
+/// pay off. Every `RawSolver` is also a [`Solver`] whose input is the unparsed string.
+pub trait RawSolver<const YEAR: u32, const DAY: u32>: Send + Sync + 'static {
+    type Part1: Answer;
+    type Part2: Answer;
+
+    fn solve_part_one(&self, input: &str) -> Self::Part1;
+    fn solve_part_two(&self, input: &str) -> Self::Part2;
+}
+
+impl<S, const YEAR: u32, const DAY: u32> Solver<YEAR, DAY> for S
+where
+    S: RawSolver<YEAR, DAY>,
+{
+    type Input = String;
+    type Part1 = S::Part1;
+    type Part2 = S::Part2;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.to_owned())
+    }
+
+    fn solve_part_one(&self, input: &Self::Input) -> Self::Part1 {
+        RawSolver::solve_part_one(self, input)
+    }
+
+    fn solve_part_two(&self, input: &Self::Input) -> Self::Part2 {
+        RawSolver::solve_part_two(self, input)
+    }
 }
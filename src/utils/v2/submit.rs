@@ -0,0 +1,119 @@
+use std::{fmt::Display, time::Duration};
+
+use anyhow::{anyhow, Result};
+use once_cell_regex::regex;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::v2::client::Client;
+
+/// The response of adventofcode.com to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited(Duration),
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Parses the HTML page returned after submitting an answer.
+    pub fn from_html(html: &str) -> Result<Verdict> {
+        let article = regex!(r"(?s)<article>(.*?)</article>")
+            .captures(html)
+            .map_or(html, |captures| captures.get(1).unwrap().as_str());
+        let text = regex!(r"<[^>]*>").replace_all(article, "");
+
+        if text.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if text.contains("You gave an answer too recently") {
+            let captures = regex!(r"(?:(\d+)m )?(\d+)s left to wait")
+                .captures(&text)
+                .ok_or_else(|| anyhow!("could not find the wait time in: {}", text.trim()))?;
+            let minutes = captures.get(1).map_or(Ok(0), |m| m.as_str().parse())?;
+            let seconds = captures[2].parse::<u64>()?;
+            Ok(Verdict::RateLimited(Duration::from_secs(
+                minutes * 60 + seconds,
+            )))
+        } else if text.contains("not the right answer") && text.contains("too high") {
+            Ok(Verdict::TooHigh)
+        } else if text.contains("not the right answer") && text.contains("too low") {
+            Ok(Verdict::TooLow)
+        } else if text.contains("not the right answer") {
+            Ok(Verdict::Wrong)
+        } else if text.contains("Did you already complete it?") {
+            Ok(Verdict::AlreadySolved)
+        } else {
+            Err(anyhow!("unrecognized response: {}", text.trim()))
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(wait) => write!(f, "rate limited ({}s left)", wait.as_secs()),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Submits an answer to `/{year}/day/{day}/answer`.
+pub fn submit_answer(
+    client: &Client,
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict> {
+    let path = format!("/{year}/day/{day}/answer");
+    let html = client.post_form(&path, &[("level", &part.to_string()), ("answer", answer)])?;
+    Verdict::from_html(&html)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rstest::rstest;
+
+    use super::{submit_answer, Verdict};
+    use crate::utils::v2::client::{Client, MockTransport};
+
+    #[rstest]
+    #[case("<main><article><p>That's the right answer!  You are one gold star closer to saving your vacation.</p></article></main>", Verdict::Correct)]
+    #[case("<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2024/day/6\">[Return to Day 6]</a></p></article>", Verdict::TooHigh)]
+    #[case("<article><p>That's not the right answer; your answer is too low.  Please wait one minute before trying again.</p></article>", Verdict::TooLow)]
+    #[case("<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article>", Verdict::Wrong)]
+    #[case("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait. <a href=\"/2024/day/6\">[Return to Day 6]</a></p></article>", Verdict::RateLimited(Duration::from_secs(34)))]
+    #[case("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 2s left to wait.</p></article>", Verdict::RateLimited(Duration::from_secs(242)))]
+    #[case("<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2024/day/6\">[Return to Day 6]</a></p></article>", Verdict::AlreadySolved)]
+    fn test_verdict_from_html(#[case] html: &str, #[case] expected: Verdict) {
+        assert_eq!(Verdict::from_html(html).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_verdict_from_unrecognized_html() {
+        assert!(Verdict::from_html("<article><p>Something else.</p></article>").is_err());
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let transport = MockTransport::default()
+            .respond(200, "<article><p>That's the right answer!</p></article>");
+        let requests = transport.requests();
+        let verdict = submit_answer(&Client::new(transport), 2024, 6, 1, "4711").unwrap();
+
+        assert_eq!(verdict, Verdict::Correct);
+        assert_eq!(
+            requests.borrow().as_slice(),
+            ["POST /2024/day/6/answer level=1&answer=4711"]
+        );
+    }
+}
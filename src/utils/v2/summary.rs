@@ -0,0 +1,211 @@
+use std::{
+    collections::BTreeSet,
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+use ansi_term::{Colour, Style};
+
+use crate::utils::v2::{
+    format::format_duration,
+    registry::DynSolver,
+    solver::{PartReport, SolveOptions},
+    worker::panic_message,
+};
+
+/// The outcome of solving both parts of a single puzzle.
+pub struct DaySummary {
+    pub year: u32,
+    pub day: u32,
+    pub outcome: Result<Vec<PartReport>, String>,
+}
+
+impl DaySummary {
+    /// Whether every part of the puzzle produced an answer, and none of them regressed.
+    pub fn is_solved(&self) -> bool {
+        self.outcome
+            .as_ref()
+            .is_ok_and(|reports| reports.iter().all(PartReport::is_solved))
+    }
+}
+
+/// Solves each puzzle in turn. Errors and panics are recorded against the puzzle which caused them
+/// instead of aborting the whole run.
+pub fn solve_all<'a>(
+    solvers: impl IntoIterator<Item = &'a dyn DynSolver>,
+    options: &SolveOptions,
+) -> Vec<DaySummary> {
+    solvers
+        .into_iter()
+        .map(|solver| {
+            let outcome = panic::catch_unwind(AssertUnwindSafe(|| solver.solve(options)));
+            let outcome = match outcome {
+                Ok(Ok(reports)) => Ok(reports),
+                Ok(Err(e)) => Err(e.to_string()),
+                Err(payload) => Err(format!("panicked: {}", panic_message(&payload))),
+            };
+            DaySummary {
+                year: solver.year(),
+                day: solver.day(),
+                outcome,
+            }
+        })
+        .collect()
+}
+
+/// Prints a table with the answers and timings of each part, followed by the total wall time.
+pub fn print_summary(summaries: &[DaySummary], wall_time: Duration) {
+    let parts = part_columns(
+        summaries
+            .iter()
+            .filter_map(|summary| summary.outcome.as_ref().ok())
+            .flatten(),
+    );
+    let header = part_header("Puzzle", &parts);
+    let rows = summaries
+        .iter()
+        .map(|summary| {
+            let mut row = vec![format!("{}/{:02}", summary.year, summary.day)];
+            if let Ok(reports) = &summary.outcome {
+                row.extend(part_cells(&parts, reports, |report| match &report.answer {
+                    Ok(answer) => table_cell(answer),
+                    Err(e) => format!("✖ {}", table_cell(e)),
+                }));
+            }
+            row
+        })
+        .collect::<Vec<_>>();
+
+    let (header, rows) = pad_table(&header, &rows);
+    println!("\n{}", Style::new().bold().paint(header));
+    for (summary, row) in summaries.iter().zip(rows) {
+        match &summary.outcome {
+            Ok(_) if summary.is_solved() => println!("{row}"),
+            Ok(_) => println!("{}", Colour::Red.paint(row)),
+            Err(e) => println!(
+                "{}  {}",
+                row,
+                Colour::Red.paint(format!("✖ {}", table_cell(e)))
+            ),
+        }
+    }
+
+    let failures = summaries.iter().filter(|s| !s.is_solved()).count();
+    println!(
+        "\n{} puzzles solved, {} failed in {}",
+        summaries.len() - failures,
+        failures,
+        format_duration(wall_time)
+    );
+}
+
+/// The parts which have a report, so that a table only has columns for the parts which were
+/// solved, e.g. only part 2 with `--part 2`. Both parts if there are no reports at all.
+pub(crate) fn part_columns<'a>(reports: impl IntoIterator<Item = &'a PartReport>) -> Vec<u8> {
+    let parts = reports
+        .into_iter()
+        .map(|report| report.part)
+        .collect::<BTreeSet<_>>();
+    if parts.is_empty() {
+        vec![1, 2]
+    } else {
+        parts.into_iter().collect()
+    }
+}
+
+/// The header of a table with the given first column, then an answer and a time for each part.
+pub(crate) fn part_header(first: &str, parts: &[u8]) -> Vec<String> {
+    let columns = parts
+        .iter()
+        .flat_map(|part| [format!("Part {part}"), "Time".to_owned()]);
+    std::iter::once(first.to_owned()).chain(columns).collect()
+}
+
+/// An answer cell and a time cell for each part, matched up by part number rather than position.
+/// Parts without a report are left blank.
+pub(crate) fn part_cells(
+    parts: &[u8],
+    reports: &[PartReport],
+    answer: impl Fn(&PartReport) -> String,
+) -> Vec<String> {
+    parts
+        .iter()
+        .flat_map(
+            |part| match reports.iter().find(|report| report.part == *part) {
+                Some(report) => [answer(report), format_duration(report.elapsed)],
+                None => [String::new(), String::new()],
+            },
+        )
+        .collect()
+}
+
+/// Pads each column of a table to the width of its widest cell, returning the header and the rows.
+pub(crate) fn pad_table(header: &[String], rows: &[Vec<String>]) -> (String, Vec<String>) {
+    let mut widths = header
+        .iter()
+        .map(|cell| cell.chars().count())
+        .collect::<Vec<_>>();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let pad = |row: &[String]| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+    };
+    (pad(header), rows.iter().map(|row| pad(row)).collect())
+}
+
+/// Keeps each row of the table on a single line, even for multi-line answers.
+pub(crate) fn table_cell(s: &str) -> String {
+    s.lines().map(str::trim).collect::<Vec<_>>().join(" ⏎ ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{part_cells, part_columns, part_header, DaySummary};
+    use crate::utils::v2::solver::{PartReport, Status};
+
+    fn report(part: u8, answer: &str) -> PartReport {
+        PartReport {
+            elapsed: Duration::from_millis(3),
+            ..PartReport::solved(part, answer)
+        }
+    }
+
+    #[test]
+    fn test_only_part_two() {
+        let reports = [report(2, "2858")];
+        let parts = part_columns(&reports);
+        assert_eq!(part_header("Puzzle", &parts), ["Puzzle", "Part 2", "Time"]);
+        let cells = part_cells(&parts, &reports, |r| r.answer.clone().unwrap());
+        assert_eq!(cells, ["2858", "3.0ms"]);
+    }
+
+    #[test]
+    fn test_missing_part() {
+        let reports = [report(2, "2858")];
+        let cells = part_cells(&[1, 2], &reports, |r| r.answer.clone().unwrap());
+        assert_eq!(cells, ["", "", "2858", "3.0ms"]);
+        assert_eq!(part_columns([]), [1, 2]);
+    }
+
+    #[test]
+    fn test_regression_is_not_solved() {
+        let mut regressed = report(2, "2857");
+        regressed.status = Status::Regressed;
+        let summary = |reports| DaySummary {
+            year: 2024,
+            day: 9,
+            outcome: Ok(reports),
+        };
+        assert!(summary(vec![report(1, "1928"), report(2, "2858")]).is_solved());
+        assert!(!summary(vec![report(1, "1928"), regressed]).is_solved());
+    }
+}
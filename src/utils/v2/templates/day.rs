@@ -0,0 +1,47 @@
+use anyhow::Result;
+
+use crate::utils::v2::solver;
+
+pub struct Solver;
+
+impl solver::Solver<{{YEAR}}, {{DAY}}> for Solver {
+    type Input = Vec<String>;
+    type Part1 = Option<i32>;
+    type Part2 = Option<i32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn solve_part_one(&self, _input: &Self::Input) -> Self::Part1 {
+        None
+    }
+
+    fn solve_part_two(&self, _input: &Self::Input) -> Self::Part2 {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::v2::solver::Solver;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", None)]
+    fn test_part_one(#[case] input: &str, #[case] expected: Option<i32>) {
+        let solver = super::Solver;
+        let input = solver.parse(input).unwrap();
+        let result = solver.solve_part_one(&input);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case("", None)]
+    fn test_part_two(#[case] input: &str, #[case] expected: Option<i32>) {
+        let solver = super::Solver;
+        let input = solver.parse(input).unwrap();
+        let result = solver.solve_part_two(&input);
+        assert_eq!(result, expected);
+    }
+}
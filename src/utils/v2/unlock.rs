@@ -0,0 +1,63 @@
+use std::{thread, time::Duration};
+
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use spinners::{Spinner, Spinners};
+
+/// Puzzles unlock at midnight EST (UTC-5) on each day of December.
+pub fn unlock_time(year: u32, day: u32) -> DateTime<Utc> {
+    FixedOffset::west_opt(5 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(year as i32, 12, day, 0, 0, 0)
+        .single()
+        .expect("puzzles should unlock on a valid date")
+        .with_timezone(&Utc)
+}
+
+/// Blocks with a live countdown until the puzzle unlocks, plus up to a second of jitter so that
+/// the first request does not arrive before the server has flipped over.
+pub fn wait_until_unlocked(year: u32, day: u32) {
+    let unlock = unlock_time(year, day);
+    if Utc::now() >= unlock {
+        return;
+    }
+
+    while let Ok(remaining) = (unlock - Utc::now()).to_std() {
+        let secs = remaining.as_secs();
+        let mut spinner = Spinner::new(
+            Spinners::Dots,
+            format!(
+                "Day {day} unlocks in {:02}:{:02}:{:02}...",
+                secs / 3600,
+                secs / 60 % 60,
+                secs % 60
+            ),
+        );
+        // Tick on each whole second of the countdown.
+        thread::sleep(remaining.min(Duration::from_nanos(remaining.subsec_nanos() as u64 + 1)));
+        spinner.stop();
+    }
+
+    let jitter = Duration::from_millis(250 + Utc::now().timestamp_subsec_millis() as u64 % 750);
+    let mut spinner = Spinner::new(Spinners::Dots, format!("Day {day} unlocked!"));
+    thread::sleep(jitter);
+    spinner.stop_and_persist("✔", format!("Day {day} unlocked"));
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use rstest::rstest;
+
+    use super::unlock_time;
+
+    #[rstest]
+    #[case(2024, 1, Utc.with_ymd_and_hms(2024, 12, 1, 5, 0, 0).unwrap())]
+    #[case(2015, 25, Utc.with_ymd_and_hms(2015, 12, 25, 5, 0, 0).unwrap())]
+    fn test_unlock_time(
+        #[case] year: u32,
+        #[case] day: u32,
+        #[case] expected: chrono::DateTime<Utc>,
+    ) {
+        assert_eq!(unlock_time(year, day), expected);
+    }
+}
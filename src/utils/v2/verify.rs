@@ -0,0 +1,224 @@
+use std::fs;
+
+use ansi_term::{Colour, Style};
+use anyhow::Result;
+
+use crate::utils::v2::{
+    config::Profile,
+    examples,
+    ledger::Ledger,
+    puzzle,
+    registry::DynSolver,
+    solver::{input_path, InputSource, PartReport, SolveOptions},
+    summary::{pad_table, part_cells, part_columns, part_header, table_cell},
+};
+
+/// One input of a puzzle, with the answer each part should produce if it is known.
+pub struct Variant {
+    pub name: String,
+    pub input: String,
+    pub source: InputSource,
+    pub expected: [Option<String>; 2],
+}
+
+/// Collects every cached input of a puzzle: the real input of each profile, checked against the
+/// answers accepted in its ledger, then each `ex*` file of `examples_from`, checked against the
+/// examples in its cached puzzle description with the same input.
+pub fn variants(
+    profiles: &[Profile],
+    examples_from: &Profile,
+    year: u32,
+    day: u32,
+) -> Result<Vec<Variant>> {
+    let mut variants = Vec::new();
+    for profile in profiles {
+        let Ok(input) = fs::read_to_string(input_path(profile, year, day, None)) else {
+            continue;
+        };
+        let ledger = Ledger::load(profile, year, day)?;
+        variants.push(Variant {
+            name: profile.display_name().to_owned(),
+            input: input.trim().to_owned(),
+            source: InputSource::File,
+            expected: [1, 2].map(|part| ledger.correct_answer(part).map(str::to_owned)),
+        });
+    }
+
+    let examples = puzzle::cached_page(examples_from, year, day)
+        .map(|html| examples::examples(&html))
+        .unwrap_or_default();
+    for (name, input) in example_files(examples_from, year, day)? {
+        let input = input.trim().to_owned();
+        let expected = [1, 2].map(|part| {
+            examples
+                .iter()
+                .find(|example| example.part == part && example.input == input)
+                .and_then(|example| example.expected.clone())
+        });
+        variants.push(Variant {
+            name,
+            input,
+            source: InputSource::Example,
+            expected,
+        });
+    }
+    Ok(variants)
+}
+
+/// Reads every `{day}.ex*` file next to the cached input, ordered by number.
+fn example_files(profile: &Profile, year: u32, day: u32) -> Result<Vec<(String, String)>> {
+    let path = input_path(profile, year, day, None);
+    let Ok(entries) = fs::read_dir(path.parent().unwrap()) else {
+        return Ok(Vec::new());
+    };
+    let prefix = format!("{day:02}.");
+    let mut files = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy().into_owned();
+        let Some(ext) = file_name.strip_prefix(&prefix) else {
+            continue;
+        };
+        if ext.starts_with("ex") {
+            files.push((ext.to_owned(), fs::read_to_string(entry.path())?));
+        }
+    }
+    files.sort_by_key(|(ext, _)| (ext[2..].parse::<u32>().unwrap_or(u32::MAX), ext.clone()));
+    Ok(files)
+}
+
+/// The outcome of solving a puzzle for one of its inputs.
+pub struct Verification {
+    pub variant: Variant,
+    pub outcome: Result<Vec<PartReport>, String>,
+}
+
+impl Verification {
+    /// Whether every part produced an answer, and every known answer matched.
+    pub fn passed(&self) -> bool {
+        self.outcome.as_ref().is_ok_and(|reports| {
+            reports.iter().all(
+                |report| match (&report.answer, self.expected(report.part)) {
+                    (Ok(answer), Some(expected)) => answer == expected,
+                    (Ok(_), None) => true,
+                    (Err(_), _) => false,
+                },
+            )
+        })
+    }
+
+    fn expected(&self, part: u8) -> Option<&str> {
+        self.variant.expected[part as usize - 1].as_deref()
+    }
+}
+
+/// Solves the puzzle for each input in turn.
+pub fn verify(
+    solver: &dyn DynSolver,
+    variants: Vec<Variant>,
+    options: &SolveOptions,
+) -> Vec<Verification> {
+    variants
+        .into_iter()
+        .map(|variant| {
+            let outcome = solver
+                .solve_input(&variant.input, variant.source, options)
+                .map_err(|e| e.to_string());
+            Verification { variant, outcome }
+        })
+        .collect()
+}
+
+/// Prints a row per input with each part marked as passed (✔), failed (✖), or unverified (?) if
+/// its answer is not known.
+pub fn print_matrix(verifications: &[Verification]) {
+    let parts = part_columns(
+        verifications
+            .iter()
+            .filter_map(|verification| verification.outcome.as_ref().ok())
+            .flatten(),
+    );
+    let header = part_header("Input", &parts);
+    let rows = verifications
+        .iter()
+        .map(|verification| {
+            let mut row = vec![verification.variant.name.clone()];
+            if let Ok(reports) = &verification.outcome {
+                row.extend(part_cells(&parts, reports, |report| {
+                    let expected = verification.expected(report.part);
+                    match (&report.answer, expected) {
+                        (Ok(answer), Some(expected)) if answer == expected => {
+                            format!("✔ {}", table_cell(answer))
+                        }
+                        (Ok(answer), Some(expected)) => format!(
+                            "✖ {} (expected {})",
+                            table_cell(answer),
+                            table_cell(expected)
+                        ),
+                        (Ok(answer), None) => format!("? {}", table_cell(answer)),
+                        (Err(e), _) => format!("✖ {}", table_cell(e)),
+                    }
+                }));
+            }
+            row
+        })
+        .collect::<Vec<_>>();
+
+    let (header, rows) = pad_table(&header, &rows);
+    println!("\n{}", Style::new().bold().paint(header));
+    for (verification, row) in verifications.iter().zip(rows) {
+        match &verification.outcome {
+            Ok(_) if verification.passed() => println!("{row}"),
+            Ok(_) => println!("{}", Colour::Red.paint(row)),
+            Err(e) => println!(
+                "{}  {}",
+                row,
+                Colour::Red.paint(format!("✖ {}", table_cell(e)))
+            ),
+        }
+    }
+
+    let failures = verifications.iter().filter(|v| !v.passed()).count();
+    println!(
+        "\n{} inputs passed, {} failed",
+        verifications.len() - failures,
+        failures
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{Variant, Verification};
+    use crate::utils::v2::solver::{InputSource, PartReport};
+
+    fn verification(answer: Result<&str, &str>, expected: Option<&str>) -> Verification {
+        Verification {
+            variant: Variant {
+                name: "ex1".to_owned(),
+                input: String::new(),
+                source: InputSource::Example,
+                expected: [expected.map(str::to_owned), None],
+            },
+            outcome: Ok(vec![PartReport {
+                answer: answer.map(str::to_owned).map_err(str::to_owned),
+                source: InputSource::Example,
+                ..PartReport::solved(1, "")
+            }]),
+        }
+    }
+
+    #[rstest]
+    #[case(Ok("11"), Some("11"), true)]
+    #[case(Ok("12"), Some("11"), false)]
+    #[case(Ok("12"), None, true)]
+    #[case(Err("timed out"), None, false)]
+    fn test_passed(
+        #[case] answer: Result<&str, &str>,
+        #[case] expected: Option<&str>,
+        #[case] passed: bool,
+    ) {
+        assert_eq!(verification(answer, expected).passed(), passed);
+    }
+}
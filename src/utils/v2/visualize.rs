@@ -0,0 +1,289 @@
+use std::{
+    collections::HashSet,
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use ansi_term::{Colour, Style};
+use anyhow::Result;
+use num::PrimInt;
+
+use crate::utils::v2::grid::DenseGrid;
+
+/// Once this many frames are recorded, every other frame is dropped and only every other frame is
+/// recorded from then on, so that long simulations still fit in memory.
+const MAX_FRAMES: usize = 2000;
+
+/// How many pixels wide and tall each cell is in a PPM image.
+const PPM_SCALE: usize = 4;
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static RECORDER: Mutex<Recorder> = Mutex::new(Recorder::new());
+
+/// A snapshot of a grid, with some of its cells highlighted, e.g. the guard walking through it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    rows: Vec<Vec<char>>,
+    highlighted: HashSet<(usize, usize)>,
+    caption: Option<String>,
+}
+
+impl Frame {
+    pub fn new(rows: Vec<Vec<char>>) -> Frame {
+        Frame {
+            rows,
+            highlighted: HashSet::new(),
+            caption: None,
+        }
+    }
+
+    /// Highlights the cell in the given row and column.
+    pub fn highlight(mut self, row: usize, col: usize) -> Frame {
+        self.highlighted.insert((row, col));
+        self
+    }
+
+    /// Replaces the cell in the given row and column, if it is in the frame.
+    pub fn set(mut self, row: usize, col: usize, c: char) -> Frame {
+        if let Some(cell) = self.rows.get_mut(row).and_then(|r| r.get_mut(col)) {
+            *cell = c;
+        }
+        self
+    }
+
+    /// A line shown below the frame when it is played.
+    pub fn caption(mut self, caption: impl Into<String>) -> Frame {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    fn width(&self) -> usize {
+        self.rows.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    fn is_highlighted(&self, row: usize, col: usize) -> bool {
+        self.highlighted.contains(&(row, col))
+    }
+}
+
+impl<I> From<&DenseGrid<char, I>> for Frame
+where
+    I: PrimInt,
+{
+    fn from(grid: &DenseGrid<char, I>) -> Self {
+        Frame::new(
+            grid.as_ndarray()
+                .rows()
+                .into_iter()
+                .map(|row| row.to_vec())
+                .collect(),
+        )
+    }
+}
+
+struct Recorder {
+    frames: Vec<Frame>,
+    /// Only every `stride`-th frame is kept.
+    stride: usize,
+    pushed: usize,
+}
+
+impl Recorder {
+    const fn new() -> Recorder {
+        Recorder {
+            frames: Vec::new(),
+            stride: 1,
+            pushed: 0,
+        }
+    }
+
+    fn push(&mut self, frame: impl FnOnce() -> Frame) {
+        self.pushed += 1;
+        if !(self.pushed - 1).is_multiple_of(self.stride) {
+            return;
+        }
+        self.frames.push(frame());
+        if self.frames.len() >= MAX_FRAMES {
+            let frames = std::mem::take(&mut self.frames);
+            self.frames = frames.into_iter().step_by(2).collect();
+            self.stride *= 2;
+        }
+    }
+}
+
+/// Whether a visualization was requested. Solvers can check this before doing any work that is
+/// only needed to build frames.
+pub fn is_recording() -> bool {
+    RECORDING.load(Ordering::Relaxed)
+}
+
+/// Records a frame if a visualization was requested, and does nothing otherwise. The frame is only
+/// built if it is going to be kept.
+pub fn record(frame: impl FnOnce() -> Frame) {
+    if !is_recording() {
+        return;
+    }
+    RECORDER.lock().unwrap().push(frame);
+}
+
+/// Discards any recorded frames and starts recording the frames pushed by solvers.
+pub fn start_recording() {
+    *RECORDER.lock().unwrap() = Recorder::new();
+    RECORDING.store(true, Ordering::Relaxed);
+}
+
+/// Stops recording and returns the frames recorded so far.
+pub fn take_frames() -> Vec<Frame> {
+    RECORDING.store(false, Ordering::Relaxed);
+    std::mem::replace(&mut *RECORDER.lock().unwrap(), Recorder::new()).frames
+}
+
+/// Plays the frames in the terminal, redrawing the screen `fps` times per second.
+pub fn play(frames: &[Frame], fps: u32) {
+    let delay = Duration::from_secs_f64(1.0 / fps.max(1) as f64);
+    for (i, frame) in frames.iter().enumerate() {
+        let status = format!("Frame {}/{}", i + 1, frames.len());
+        let caption = match &frame.caption {
+            Some(caption) => format!("{status}: {caption}"),
+            None => status,
+        };
+        print!(
+            "\x1B[2J\x1B[H{}\n{}\n",
+            render(frame),
+            Style::new().dimmed().paint(caption)
+        );
+        std::io::stdout().flush().ok();
+        thread::sleep(delay);
+    }
+}
+
+/// Draws the frame with each kind of cell in its own colour, and highlighted cells in bold red.
+fn render(frame: &Frame) -> String {
+    frame
+        .rows
+        .iter()
+        .enumerate()
+        .map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .map(|(col, &c)| {
+                    let style = if frame.is_highlighted(row, col) {
+                        Colour::Red.bold()
+                    } else {
+                        match c {
+                            '.' | ' ' => Style::new().dimmed(),
+                            '#' => Style::new(),
+                            _ => Style::new().fg(PALETTE[c as usize % PALETTE.len()].0),
+                        }
+                    };
+                    style.paint(c.to_string()).to_string()
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The colours of cells other than walls and empty space, in the terminal and in images.
+const PALETTE: [(Colour, [u8; 3]); 6] = [
+    (Colour::Cyan, [0, 170, 170]),
+    (Colour::Green, [0, 170, 0]),
+    (Colour::Yellow, [200, 170, 0]),
+    (Colour::Blue, [60, 90, 220]),
+    (Colour::Purple, [170, 0, 170]),
+    (Colour::White, [170, 170, 170]),
+];
+
+fn rgb(c: char, highlighted: bool) -> [u8; 3] {
+    match c {
+        _ if highlighted => [220, 30, 30],
+        '.' | ' ' => [15, 15, 35],
+        '#' => [230, 230, 230],
+        _ => PALETTE[c as usize % PALETTE.len()].1,
+    }
+}
+
+/// Encodes the frame as a binary PPM image, with each cell drawn as a square of pixels.
+fn to_ppm(frame: &Frame) -> Vec<u8> {
+    let (width, height) = (frame.width() * PPM_SCALE, frame.rows.len() * PPM_SCALE);
+    let mut ppm = format!("P6\n{width} {height}\n255\n").into_bytes();
+    for (row, cells) in frame.rows.iter().enumerate() {
+        let mut line = Vec::with_capacity(width * 3);
+        for col in 0..frame.width() {
+            let c = cells.get(col).copied().unwrap_or(' ');
+            let pixel = rgb(c, frame.is_highlighted(row, col));
+            for _ in 0..PPM_SCALE {
+                line.extend_from_slice(&pixel);
+            }
+        }
+        for _ in 0..PPM_SCALE {
+            ppm.extend_from_slice(&line);
+        }
+    }
+    ppm
+}
+
+/// Writes each frame to `dir` as `frame-00001.ppm`, `frame-00002.ppm`, and so on.
+pub fn write_ppm(frames: &[Frame], dir: &Path) -> Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let mut paths = Vec::new();
+    for (i, frame) in (1..).zip(frames) {
+        let path = dir.join(format!("frame-{i:05}.ppm"));
+        fs::write(&path, to_ppm(frame))?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::{render, to_ppm, Frame, Recorder, MAX_FRAMES};
+    use crate::utils::v2::grid::DenseGrid;
+
+    fn frame() -> Frame {
+        let grid = DenseGrid::<char, i32>::try_from("#..\n.^.").unwrap();
+        Frame::from(&grid).set(1, 1, '.').highlight(1, 1)
+    }
+
+    #[test]
+    fn test_ppm() {
+        let ppm = to_ppm(&frame());
+        let header = b"P6\n12 8\n255\n";
+        assert!(ppm.starts_with(header));
+        assert_eq!(ppm.len(), header.len() + 12 * 8 * 3);
+        // The top left cell is a wall, and the centre of the bottom row is highlighted.
+        assert_eq!(ppm[header.len()..header.len() + 3], [230, 230, 230]);
+        let highlighted = header.len() + (4 * 12 + 4) * 3;
+        assert_eq!(ppm[highlighted..highlighted + 3], [220, 30, 30]);
+    }
+
+    #[test]
+    fn test_render() {
+        let rendered = render(&frame());
+        assert_eq!(rendered.lines().count(), 2);
+        assert!(rendered.contains(&ansi_term::Colour::Red.bold().paint(".").to_string()));
+    }
+
+    #[rstest]
+    #[case(10, 10)]
+    #[case(MAX_FRAMES, MAX_FRAMES / 2)]
+    #[case(MAX_FRAMES * 3, MAX_FRAMES * 3 / 4)]
+    fn test_recorder(#[case] pushed: usize, #[case] expected: usize) {
+        let mut recorder = Recorder::new();
+        for i in 0..pushed {
+            recorder.push(|| Frame::new(vec![]).caption(i.to_string()));
+        }
+        assert_eq!(recorder.frames.len(), expected);
+        assert_eq!(recorder.frames[0].caption.as_deref(), Some("0"));
+    }
+}
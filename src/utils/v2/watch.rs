@@ -0,0 +1,216 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use ansi_term::{Colour, Style};
+use anyhow::{anyhow, Context, Result};
+use json::JsonValue;
+
+use crate::utils::v2::{
+    registry::DynSolver,
+    solver::{input_path, SolveOptions},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Carries the answers of the last run across a re-exec, so that they can still be diffed.
+const PREVIOUS_ANSWERS_VAR: &str = "AOC_WATCH_PREVIOUS_ANSWERS";
+
+/// The answer (or error) of each part, as shown to the user.
+type Answers = BTreeMap<u8, String>;
+
+/// Solves the puzzle, then solves it again whenever its input or example files change. When the
+/// solver's source file changes, the binary is rebuilt and re-executed with the same arguments
+/// instead. Never returns unless the watch itself fails.
+pub fn watch(solver: &dyn DynSolver, options: &SolveOptions) -> Result<()> {
+    let (year, day) = (solver.year(), solver.day());
+    // After a rebuild this would be the path of the deleted binary, so it is looked up first.
+    let exe = env::current_exe()?;
+    let source = PathBuf::from(format!("src/aoc{year}/day{day:02}.rs"));
+    let input_dir = input_path(&options.profile, year, day, None)
+        .parent()
+        .unwrap()
+        .to_owned();
+    let prefix = format!("{day:02}.");
+
+    let mut previous = env::var(PREVIOUS_ANSWERS_VAR)
+        .ok()
+        .and_then(|answers| decode(&answers));
+    // The input is downloaded again at most once, not on every change, nor after every rebuild
+    // (which re-executes with the same arguments).
+    let mut options = SolveOptions {
+        refetch: options.refetch && env::var_os(PREVIOUS_ANSWERS_VAR).is_none(),
+        ..options.clone()
+    };
+    let mut source_modified = modified(&source);
+    loop {
+        print!("\x1B[2J\x1B[H");
+        let answers = solve(solver, &options);
+        options.refetch = false;
+        if let Some(previous) = &previous {
+            println!();
+            for line in diff(previous, &answers) {
+                println!("{line}");
+            }
+        }
+        println!(
+            "\n{}",
+            Style::new().dimmed().paint(format!(
+                "Watching {} and {}/{prefix}* for changes...",
+                source.display(),
+                input_dir.display()
+            ))
+        );
+
+        let inputs = input_files(&input_dir, &prefix);
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            if modified(&source) != source_modified {
+                source_modified = modified(&source);
+                println!("\n{} changed, rebuilding...", source.display());
+                match rebuild() {
+                    Ok(()) => return reexec(&exe, &answers),
+                    Err(e) => eprintln!("{}", Colour::Red.paint(format!("✖ {e}"))),
+                }
+            }
+            if input_files(&input_dir, &prefix) != inputs {
+                break;
+            }
+        }
+        previous = Some(answers);
+    }
+}
+
+fn solve(solver: &dyn DynSolver, options: &SolveOptions) -> Answers {
+    let Ok(reports) = solver.solve(options) else {
+        return Answers::new();
+    };
+    reports
+        .into_iter()
+        .map(|report| {
+            let answer = report.answer.unwrap_or_else(|e| format!("✖ {e}"));
+            (report.part, answer)
+        })
+        .collect()
+}
+
+/// Compares the answers of each part with those of the previous run.
+fn diff(previous: &Answers, current: &Answers) -> Vec<String> {
+    current
+        .iter()
+        .map(|(part, answer)| match previous.get(part) {
+            Some(before) if before == answer => format!("Part {part}: {answer} (unchanged)"),
+            Some(before) => Colour::Yellow
+                .paint(format!("Part {part}: {before} → {answer}"))
+                .to_string(),
+            None => Colour::Yellow
+                .paint(format!("Part {part}: {answer} (new)"))
+                .to_string(),
+        })
+        .collect()
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// The modification time of every input and example file of the day, except the puzzle page.
+fn input_files(dir: &Path, prefix: &str) -> BTreeMap<PathBuf, Option<SystemTime>> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return BTreeMap::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            name.starts_with(prefix) && !name.ends_with(".html")
+        })
+        .map(|path| {
+            let modified = modified(&path);
+            (path, modified)
+        })
+        .collect()
+}
+
+fn rebuild() -> Result<()> {
+    let mut cargo = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned()));
+    cargo.arg("build");
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    let status = cargo.status().context("failed to run cargo")?;
+    if !status.success() {
+        return Err(anyhow!("the build failed, still watching"));
+    }
+    Ok(())
+}
+
+/// Replaces this process with the rebuilt binary, passing the answers of the last run along.
+fn reexec(exe: &Path, answers: &Answers) -> Result<()> {
+    let mut command = Command::new(exe);
+    command
+        .args(env::args_os().skip(1))
+        .env(PREVIOUS_ANSWERS_VAR, encode(answers));
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        Err(anyhow!(
+            "failed to re-execute {}: {}",
+            exe.display(),
+            command.exec()
+        ))
+    }
+    #[cfg(not(unix))]
+    {
+        let status = command.status()?;
+        std::process::exit(status.code().unwrap_or(1));
+    }
+}
+
+fn encode(answers: &Answers) -> String {
+    let mut object = JsonValue::new_object();
+    for (part, answer) in answers {
+        object[part.to_string()] = answer.as_str().into();
+    }
+    object.dump()
+}
+
+fn decode(answers: &str) -> Option<Answers> {
+    json::parse(answers)
+        .ok()?
+        .entries()
+        .map(|(part, answer)| Some((part.parse().ok()?, answer.as_str()?.to_owned())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, diff, encode, Answers};
+
+    fn answers(answers: &[(u8, &str)]) -> Answers {
+        answers
+            .iter()
+            .map(|(part, answer)| (*part, answer.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff() {
+        let previous = answers(&[(1, "1928"), (2, "2857")]);
+        let current = answers(&[(1, "1928"), (2, "2858")]);
+        let diff = diff(&previous, &current);
+        assert_eq!(diff[0], "Part 1: 1928 (unchanged)");
+        assert!(diff[1].contains("Part 2: 2857 → 2858"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let answers = answers(&[(1, "1928"), (2, "✖ timed out")]);
+        assert_eq!(decode(&encode(&answers)), Some(answers));
+    }
+}
@@ -0,0 +1,168 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Once},
+    thread,
+    time::Duration,
+};
+
+/// The stack size of worker threads in MiB, unless overridden with `AOC_STACK_SIZE_MB`. Parts used
+/// to run on the main thread, whose stack is usually 8 MiB, and some recursive solvers rely on that.
+const DEFAULT_STACK_SIZE_MB: usize = 64;
+
+/// How a job run on a worker thread ended.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome<T> {
+    Finished(T),
+    /// The job panicked, with the panic message and where it happened.
+    Panicked(String),
+    TimedOut,
+}
+
+thread_local! {
+    static IS_WORKER: Cell<bool> = const { Cell::new(false) };
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Replaces the panic hook so that panics on worker threads are recorded instead of being printed
+/// over the spinner. Panics on any other thread still go to the previous hook.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if IS_WORKER.get() {
+                let location = info
+                    .location()
+                    .map(|l| format!("{}:{}", l.file(), l.line()));
+                PANIC_LOCATION.set(location);
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Runs `job` on its own thread, giving up on it after `timeout` if one is given. A job which
+/// times out is left running in the background, since threads cannot be cancelled.
+pub fn run<T, F>(name: String, timeout: Option<Duration>, job: F) -> Outcome<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .name(name)
+        .stack_size(stack_size())
+        .spawn(move || {
+            let outcome = match catch(job) {
+                Ok(result) => Outcome::Finished(result),
+                Err(message) => Outcome::Panicked(message),
+            };
+            // The receiver is gone if the job timed out, in which case nobody wants the outcome.
+            let _ = sender.send(outcome);
+        });
+    if let Err(e) = spawned {
+        return Outcome::Panicked(format!("failed to spawn a worker thread: {e}"));
+    }
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).map_err(|e| match e {
+            mpsc::RecvTimeoutError::Timeout => Outcome::TimedOut,
+            mpsc::RecvTimeoutError::Disconnected => Outcome::Panicked("worker thread died".into()),
+        }),
+        None => receiver
+            .recv()
+            .map_err(|_| Outcome::Panicked("worker thread died".into())),
+    };
+    received.unwrap_or_else(|outcome| outcome)
+}
+
+/// A stack overflow aborts the whole process instead of panicking, so worker threads get a stack at
+/// least as large as the main thread's.
+fn stack_size() -> usize {
+    dotenv::dotenv().ok();
+    let mb = dotenv::var("AOC_STACK_SIZE_MB")
+        .ok()
+        .and_then(|mb| mb.parse().ok())
+        .unwrap_or(DEFAULT_STACK_SIZE_MB);
+    mb * 1024 * 1024
+}
+
+/// Runs `job` on the current thread, returning the panic message and where it happened if it
+/// panics. Like on worker threads, the panic is not printed.
+pub fn catch<T>(job: impl FnOnce() -> T) -> Result<T, String> {
+    install_panic_hook();
+    let was_worker = IS_WORKER.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(job)).map_err(|payload| {
+        let message = panic_message(&payload);
+        match PANIC_LOCATION.take() {
+            Some(location) => format!("{message} at {location}"),
+            None => message.to_owned(),
+        }
+    });
+    IS_WORKER.set(was_worker);
+    result
+}
+
+pub fn panic_message(payload: &Box<dyn Any + Send>) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown cause"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::{catch, run, Outcome};
+
+    #[test]
+    fn test_finished() {
+        assert_eq!(run("test".into(), None, || 42), Outcome::Finished(42));
+    }
+
+    #[test]
+    fn test_panicked() {
+        let outcome = run::<(), _>("test".into(), None, || panic!("oh no"));
+        let Outcome::Panicked(message) = outcome else {
+            panic!("expected a panic, got {outcome:?}");
+        };
+        assert!(message.starts_with("oh no at src/utils/v2/worker.rs:"));
+    }
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(catch(|| 42), Ok(42));
+        let message = catch(|| panic!("oh no")).unwrap_err();
+        assert!(message.starts_with("oh no at src/utils/v2/worker.rs:"));
+    }
+
+    #[test]
+    fn test_deep_recursion() {
+        // Would overflow the default stack of 2 MiB for spawned threads.
+        fn depth(n: u64) -> u64 {
+            let padding = std::hint::black_box([0u8; 256]);
+            match n {
+                0 => padding[0] as u64,
+                n => 1 + depth(n - 1),
+            }
+        }
+        let outcome = run("test".into(), None, || depth(50_000));
+        assert_eq!(outcome, Outcome::Finished(50_000));
+    }
+
+    #[test]
+    fn test_timed_out() {
+        let timeout = Some(Duration::from_millis(10));
+        let outcome = run("test".into(), timeout, || {
+            thread::sleep(Duration::from_secs(1))
+        });
+        assert_eq!(outcome, Outcome::TimedOut);
+    }
+}